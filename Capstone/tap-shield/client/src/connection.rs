@@ -0,0 +1,113 @@
+use anchor_lang::prelude::Pubkey;
+use solana_sdk::{hash::Hash, signature::Signature, transaction::Transaction};
+
+use crate::error::Result;
+
+/// What the client needs from a cluster. Implemented for `RpcClient` with the `rpc`
+/// feature; tests implement it over an in-process SVM.
+pub trait Connection {
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>>;
+
+    /// TapShield accounts whose data matches every `(offset, bytes)` filter.
+    fn get_program_accounts(&self, filters: &[(usize, Vec<u8>)]) -> Result<Vec<(Pubkey, Vec<u8>)>>;
+
+    /// Lamports an account of `data_len` bytes needs to be rent-exempt on this cluster.
+    fn minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64>;
+
+    fn latest_blockhash(&mut self) -> Result<Hash>;
+
+    fn send_transaction(&mut self, transaction: &Transaction) -> Result<Signature>;
+
+    /// Runs `transaction` without committing it and returns the return data it left, if any.
+    fn simulate_transaction(&self, transaction: &Transaction) -> Result<Option<Vec<u8>>>;
+}
+
+#[cfg(feature = "rpc")]
+mod rpc {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use solana_client::{
+        client_error::ClientError as RpcError,
+        rpc_client::RpcClient,
+        rpc_config::RpcProgramAccountsConfig,
+        rpc_filter::{Memcmp, RpcFilterType},
+    };
+
+    use super::*;
+    use crate::error::ClientError;
+
+    fn map_err(error: RpcError) -> ClientError {
+        match error.get_transaction_error() {
+            Some(error) => error.into(),
+            None => ClientError::Connection(error.to_string()),
+        }
+    }
+
+    impl Connection for RpcClient {
+        fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+            let response = self
+                .get_account_with_commitment(address, self.commitment())
+                .map_err(map_err)?;
+
+            Ok(response.value.map(|account| account.data))
+        }
+
+        fn get_program_accounts(
+            &self,
+            filters: &[(usize, Vec<u8>)],
+        ) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+            let config = RpcProgramAccountsConfig {
+                filters: Some(
+                    filters
+                        .iter()
+                        .map(|(offset, bytes)| {
+                            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(*offset, bytes.clone()))
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            };
+
+            let accounts = self
+                .get_program_accounts_with_config(&tap_shield::ID, config)
+                .map_err(map_err)?;
+
+            Ok(accounts
+                .into_iter()
+                .map(|(address, account)| (address, account.data))
+                .collect())
+        }
+
+        fn minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64> {
+            self.get_minimum_balance_for_rent_exemption(data_len)
+                .map_err(map_err)
+        }
+
+        fn latest_blockhash(&mut self) -> Result<Hash> {
+            self.get_latest_blockhash().map_err(map_err)
+        }
+
+        fn send_transaction(&mut self, transaction: &Transaction) -> Result<Signature> {
+            self.send_and_confirm_transaction(transaction)
+                .map_err(map_err)
+        }
+
+        fn simulate_transaction(&self, transaction: &Transaction) -> Result<Option<Vec<u8>>> {
+            let result = RpcClient::simulate_transaction(self, transaction)
+                .map_err(map_err)?
+                .value;
+
+            if let Some(error) = result.err {
+                return Err(error.into());
+            }
+
+            result
+                .return_data
+                .map(|return_data| {
+                    STANDARD
+                        .decode(return_data.data.0)
+                        .map_err(|error| ClientError::Connection(error.to_string()))
+                })
+                .transpose()
+        }
+    }
+}
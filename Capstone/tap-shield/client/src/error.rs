@@ -0,0 +1,156 @@
+use anchor_lang::{error::ERROR_CODE_OFFSET, prelude::Pubkey};
+use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+use tap_shield::errors::TapShieldErr;
+
+/// Every `TapShieldErr` variant in declaration order; codes are `ERROR_CODE_OFFSET + index`.
+const PROGRAM_ERRORS: &[TapShieldErr] = &[
+    TapShieldErr::UnauthorizedSigner,
+    TapShieldErr::UnauthorizedFaucet,
+    TapShieldErr::UnregisteredFaucet,
+    TapShieldErr::ClaimAlreadyExists,
+    TapShieldErr::FaucetAlreadyExists,
+    TapShieldErr::ClaimTooRecent,
+    TapShieldErr::InvalidClaimer,
+    TapShieldErr::InvalidTimestamp,
+    TapShieldErr::InvalidCooldown,
+    TapShieldErr::InvalidInput,
+    TapShieldErr::FaucetNameTooLong,
+    TapShieldErr::GlobalCooldownActive,
+    TapShieldErr::InvalidQuota,
+    TapShieldErr::ClaimQuotaExceeded,
+    TapShieldErr::AmountQuotaExceeded,
+    TapShieldErr::InsufficientVaultBalance,
+    TapShieldErr::FaucetMintAlreadyBound,
+    TapShieldErr::FaucetMintMismatch,
+    TapShieldErr::ClaimerSignatureRequired,
+    TapShieldErr::TooManyAttesters,
+    TapShieldErr::AttestationMissing,
+    TapShieldErr::InvalidAttestation,
+    TapShieldErr::UnknownAttester,
+    TapShieldErr::AttestationExpired,
+    TapShieldErr::TooManyRecorders,
+    TapShieldErr::RecorderAlreadyExists,
+    TapShieldErr::RecorderNotFound,
+    TapShieldErr::NotPendingOwner,
+    TapShieldErr::WalletBlocked,
+    TapShieldErr::WalletNotAllowlisted,
+    TapShieldErr::ReasonTooLong,
+    TapShieldErr::InvalidClaimRecord,
+    TapShieldErr::ClaimRecordStillActive,
+    TapShieldErr::InvalidBudget,
+    TapShieldErr::BudgetExhausted,
+    TapShieldErr::UnboundCaller,
+    TapShieldErr::FaucetPaused,
+    TapShieldErr::FaucetVaultNotEmpty,
+    TapShieldErr::MetadataTooLong,
+    TapShieldErr::AccountAlreadyMigrated,
+    TapShieldErr::UnsupportedAccountVersion,
+    TapShieldErr::BondRequired,
+    TapShieldErr::BondLocked,
+    TapShieldErr::InsufficientBond,
+    TapShieldErr::ClusterCooldownActive,
+    TapShieldErr::ClusterMismatch,
+    TapShieldErr::ReputationTooLow,
+    TapShieldErr::ReputationAmountExceeded,
+    TapShieldErr::InvalidBatchAccounts,
+    TapShieldErr::ClaimLogMismatch,
+    TapShieldErr::FaucetHasClaims,
+    TapShieldErr::ReputationNotApproved,
+    TapShieldErr::FlagLimitReached,
+    TapShieldErr::FaucetHasPendingReports,
+    TapShieldErr::FaucetHasOpenClaimRecords,
+    TapShieldErr::BondNotRequired,
+];
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The program rejected the transaction with one of its own errors.
+    Program(TapShieldErr),
+    /// Any other custom error code, e.g. an Anchor framework error.
+    Custom(u32),
+    Transaction(TransactionError),
+    AccountNotFound(Pubkey),
+    AccountDidNotDeserialize(Pubkey),
+    /// A simulated instruction did not return the data it was expected to.
+    MissingReturnData,
+    Connection(String),
+}
+
+impl ClientError {
+    pub fn from_code(code: u32) -> Self {
+        code.checked_sub(ERROR_CODE_OFFSET)
+            .and_then(|index| PROGRAM_ERRORS.get(index as usize))
+            .map_or(Self::Custom(code), |error| Self::Program(*error))
+    }
+
+    /// The program error this maps to, if any.
+    pub fn program_error(&self) -> Option<TapShieldErr> {
+        match self {
+            Self::Program(error) => Some(*error),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransactionError> for ClientError {
+    fn from(error: TransactionError) -> Self {
+        match error {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+                Self::from_code(code)
+            }
+            error => Self::Transaction(error),
+        }
+    }
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Program(error) => write!(f, "program error: {}", error.name()),
+            Self::Custom(code) => write!(f, "custom program error: {code}"),
+            Self::Transaction(error) => write!(f, "transaction error: {error}"),
+            Self::AccountNotFound(address) => write!(f, "account {address} not found"),
+            Self::AccountDidNotDeserialize(address) => {
+                write!(f, "account {address} did not deserialize")
+            }
+            Self::MissingReturnData => write!(f, "instruction returned no data"),
+            Self::Connection(message) => write!(f, "connection error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn program_errors_match_their_codes() {
+        // Variant names in declaration order, read from the enum's source.
+        let variants: Vec<&str> = include_str!("../../programs/tap-shield/src/errors.rs")
+            .lines()
+            .map(str::trim)
+            .filter_map(|line| line.strip_suffix(','))
+            .filter(|name| !name.starts_with('#'))
+            .collect();
+        assert_eq!(PROGRAM_ERRORS.len(), variants.len());
+
+        for (index, (error, name)) in PROGRAM_ERRORS.iter().zip(variants).enumerate() {
+            assert_eq!(error.name(), name);
+            assert_eq!(
+                u32::from(*error),
+                ERROR_CODE_OFFSET + index as u32,
+                "{name}"
+            );
+        }
+
+        let past_the_end = ERROR_CODE_OFFSET + PROGRAM_ERRORS.len() as u32;
+        assert!(matches!(
+            ClientError::from_code(past_the_end),
+            ClientError::Custom(code) if code == past_the_end
+        ));
+    }
+}
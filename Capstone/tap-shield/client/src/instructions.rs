@@ -0,0 +1,232 @@
+//! Instruction builders for callers that assemble their own transactions.
+
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    system_program, InstructionData, ToAccountMetas,
+};
+use tap_shield::instructions::{BatchClaim, BatchMode};
+
+use crate::pda;
+
+/// `admin` must be the program's upgrade authority.
+pub fn initialize_config(admin: &Pubkey, global_cooldown_seconds: i64) -> Instruction {
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts: tap_shield::accounts::InitializeConfig {
+            admin: *admin,
+            protocol_config: pda::protocol_config(),
+            program_data: pda::program_data(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: tap_shield::instruction::InitializeConfig {
+            global_cooldown_seconds,
+        }
+        .data(),
+    }
+}
+
+pub fn initialize_faucet(
+    owner: &Pubkey,
+    faucet_id: &Pubkey,
+    name: &str,
+    cooldown_seconds: i64,
+) -> Instruction {
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts: tap_shield::accounts::InitializeFaucet {
+            owner: *owner,
+            faucet_registry: pda::faucet_registry(faucet_id),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: tap_shield::instruction::InitializeFaucet {
+            faucet_id: *faucet_id,
+            name: name.to_string(),
+            cooldown_seconds,
+        }
+        .data(),
+    }
+}
+
+/// `claim_index` is the faucet's current `total_claims`; it seeds the new claim record.
+/// `wallet_cluster` is the claimer's `ClusterMembership.cluster`, if they are linked.
+/// `claim_log` is the faucet's `claim_log`; with one, no claim record is created.
+#[allow(clippy::too_many_arguments)]
+pub fn record_claim(
+    operator: &Pubkey,
+    faucet_id: &Pubkey,
+    claimer: &Pubkey,
+    claim_index: u64,
+    amount: u64,
+    wallet_cluster: Option<Pubkey>,
+    claim_log: Option<Pubkey>,
+) -> Instruction {
+    let faucet_registry = pda::faucet_registry(faucet_id);
+
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts: tap_shield::accounts::RecordClaim {
+            operator: *operator,
+            claimer: *claimer,
+            faucet_registry,
+            protocol_config: pda::protocol_config(),
+            claim_record: claim_log
+                .is_none()
+                .then(|| pda::claim_record(claimer, &faucet_registry, claim_index)),
+            claim_log,
+            user_claim_registry: pda::user_claim_registry(claimer),
+            user_faucet_state: pda::user_faucet_state(claimer, &faucet_registry),
+            protocol_block_entry: pda::protocol_block_entry(claimer),
+            faucet_list_entry: pda::faucet_list_entry(&faucet_registry, claimer),
+            claimer_bond: pda::claimer_bond(claimer),
+            cluster_membership: pda::cluster_membership(&faucet_registry, claimer),
+            wallet_cluster,
+            instruction_sysvar: None,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: tap_shield::instruction::RecordClaim {
+            claimer_pubkey: *claimer,
+            amount,
+        }
+        .data(),
+    }
+}
+
+/// Same as [`record_claim`], with the claimer as a co-signer. Signed claims update the
+/// claimer's global user registry at every faucet, and bonded faucets accept nothing else.
+#[allow(clippy::too_many_arguments)]
+pub fn record_signed_claim(
+    operator: &Pubkey,
+    faucet_id: &Pubkey,
+    claimer: &Pubkey,
+    claim_index: u64,
+    amount: u64,
+    wallet_cluster: Option<Pubkey>,
+    claim_log: Option<Pubkey>,
+) -> Instruction {
+    let mut instruction = record_claim(
+        operator,
+        faucet_id,
+        claimer,
+        claim_index,
+        amount,
+        wallet_cluster,
+        claim_log,
+    );
+
+    for meta in &mut instruction.accounts {
+        if meta.pubkey == *claimer {
+            meta.is_signer = true;
+        }
+    }
+
+    instruction
+}
+
+/// `wallet_cluster` is the claimer's `ClusterMembership.cluster`, if they are linked.
+pub fn check_eligibility(
+    faucet_id: &Pubkey,
+    claimer: &Pubkey,
+    amount: u64,
+    wallet_cluster: Option<Pubkey>,
+) -> Instruction {
+    let faucet_registry = pda::faucet_registry(faucet_id);
+
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts: tap_shield::accounts::CheckEligibility {
+            claimer: *claimer,
+            faucet_registry,
+            protocol_config: pda::protocol_config(),
+            user_claim_registry: pda::user_claim_registry(claimer),
+            user_faucet_state: pda::user_faucet_state(claimer, &faucet_registry),
+            protocol_block_entry: pda::protocol_block_entry(claimer),
+            faucet_list_entry: pda::faucet_list_entry(&faucet_registry, claimer),
+            claimer_bond: pda::claimer_bond(claimer),
+            cluster_membership: pda::cluster_membership(&faucet_registry, claimer),
+            wallet_cluster,
+        }
+        .to_account_metas(None),
+        data: tap_shield::instruction::CheckEligibility { amount }.data(),
+    }
+}
+
+/// `claims` are `(claimer, amount, wallet_cluster)`, with `wallet_cluster` and `claim_log`
+/// as in [`record_claim`].
+pub fn record_claims_batch(
+    operator: &Pubkey,
+    faucet_id: &Pubkey,
+    claims: &[(Pubkey, u64, Option<Pubkey>)],
+    mode: BatchMode,
+    claim_log: Option<Pubkey>,
+) -> Instruction {
+    let faucet_registry = pda::faucet_registry(faucet_id);
+
+    let mut accounts = tap_shield::accounts::RecordClaimsBatch {
+        operator: *operator,
+        faucet_registry,
+        protocol_config: pda::protocol_config(),
+        claim_log,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+
+    for (claimer, _, wallet_cluster) in claims {
+        accounts.extend([
+            AccountMeta::new(pda::user_claim_registry(claimer), false),
+            AccountMeta::new(pda::user_faucet_state(claimer, &faucet_registry), false),
+            AccountMeta::new_readonly(pda::protocol_block_entry(claimer), false),
+            AccountMeta::new_readonly(pda::faucet_list_entry(&faucet_registry, claimer), false),
+            AccountMeta::new_readonly(pda::claimer_bond(claimer), false),
+            AccountMeta::new_readonly(pda::cluster_membership(&faucet_registry, claimer), false),
+            match wallet_cluster {
+                Some(wallet_cluster) => AccountMeta::new(*wallet_cluster, false),
+                None => AccountMeta::new_readonly(tap_shield::ID, false),
+            },
+        ]);
+    }
+
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts,
+        data: tap_shield::instruction::RecordClaimsBatch {
+            claims: claims
+                .iter()
+                .map(|&(claimer, amount, _)| BatchClaim { claimer, amount })
+                .collect(),
+            mode,
+        }
+        .data(),
+    }
+}
+
+/// `claim_log` must already be allocated with `ClaimLog::SPACE` bytes and owned by the
+/// program.
+pub fn init_claim_log(owner: &Pubkey, faucet_id: &Pubkey, claim_log: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts: tap_shield::accounts::InitClaimLog {
+            owner: *owner,
+            faucet_registry: pda::faucet_registry(faucet_id),
+            claim_log: *claim_log,
+        }
+        .to_account_metas(None),
+        data: tap_shield::instruction::InitClaimLog {}.data(),
+    }
+}
+
+pub fn close_claim_log(owner: &Pubkey, faucet_id: &Pubkey, claim_log: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts: tap_shield::accounts::CloseClaimLog {
+            owner: *owner,
+            faucet_registry: pda::faucet_registry(faucet_id),
+            claim_log: *claim_log,
+        }
+        .to_account_metas(None),
+        data: tap_shield::instruction::CloseClaimLog {}.data(),
+    }
+}
@@ -0,0 +1,314 @@
+//! Rust counterpart of the TypeScript SDK: PDA helpers, instruction builders, account
+//! readers and typed program errors, over any [`Connection`].
+
+pub mod connection;
+pub mod error;
+pub mod instructions;
+pub mod pda;
+
+use anchor_lang::{
+    prelude::Pubkey, solana_program::instruction::Instruction, AccountDeserialize,
+    AnchorDeserialize, Discriminator,
+};
+use solana_sdk::{
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+use solana_system_interface::instruction as system_instruction;
+use tap_shield::{
+    instructions::{BatchMode, Eligibility},
+    states::{
+        ClaimLog, ClaimRecord, ClusterMembership, FaucetRegistry, UserClaimRegistry,
+        UserFaucetState,
+    },
+};
+
+pub use connection::Connection;
+pub use error::{ClientError, Result};
+pub use tap_shield::errors::TapShieldErr;
+
+/// Offset of `ClaimRecord.claimer`: discriminator plus the version byte.
+const CLAIM_RECORD_CLAIMER_OFFSET: usize = 9;
+
+pub struct TapShieldClient<C> {
+    connection: C,
+    payer: Keypair,
+    faucet_id: Pubkey,
+}
+
+impl<C: Connection> TapShieldClient<C> {
+    /// `payer` signs as faucet owner/operator; the faucet id defaults to its public key,
+    /// like the TypeScript SDK.
+    pub fn new(connection: C, payer: Keypair) -> Self {
+        let faucet_id = payer.pubkey();
+
+        Self {
+            connection,
+            payer,
+            faucet_id,
+        }
+    }
+
+    pub fn with_faucet_id(mut self, faucet_id: Pubkey) -> Self {
+        self.faucet_id = faucet_id;
+        self
+    }
+
+    pub fn connection(&self) -> &C {
+        &self.connection
+    }
+
+    pub fn connection_mut(&mut self) -> &mut C {
+        &mut self.connection
+    }
+
+    pub fn payer(&self) -> &Keypair {
+        &self.payer
+    }
+
+    pub fn faucet_id(&self) -> Pubkey {
+        self.faucet_id
+    }
+
+    pub fn faucet_registry(&self) -> Pubkey {
+        pda::faucet_registry(&self.faucet_id)
+    }
+
+    pub fn register_faucet(&mut self, name: &str, cooldown_seconds: i64) -> Result<Signature> {
+        let instruction = instructions::initialize_faucet(
+            &self.payer.pubkey(),
+            &self.faucet_id,
+            name,
+            cooldown_seconds,
+        );
+
+        self.send(&[instruction], &[])
+    }
+
+    /// Records a claim for `claimer` and returns the new claim record address, or the
+    /// claim log address for faucets that have one.
+    pub fn record_claim(&mut self, claimer: &Pubkey, amount: u64) -> Result<Pubkey> {
+        self.record_claim_for(claimer, amount, None)
+    }
+
+    /// Same as [`Self::record_claim`], co-signed by `claimer`; signed claims update the
+    /// claimer's global user registry even at faucets the admin hasn't approved for it.
+    pub fn record_signed_claim(&mut self, claimer: &Keypair, amount: u64) -> Result<Pubkey> {
+        self.record_claim_for(&claimer.pubkey(), amount, Some(claimer))
+    }
+
+    fn record_claim_for(
+        &mut self,
+        claimer: &Pubkey,
+        amount: u64,
+        claimer_signer: Option<&Keypair>,
+    ) -> Result<Pubkey> {
+        let faucet = self.get_faucet_stats()?;
+        let claim_index = faucet.total_claims;
+        let wallet_cluster = self
+            .get_cluster_membership(claimer)?
+            .map(|membership| membership.cluster);
+        let build = match claimer_signer {
+            Some(_) => instructions::record_signed_claim,
+            None => instructions::record_claim,
+        };
+        let instruction = build(
+            &self.payer.pubkey(),
+            &self.faucet_id,
+            claimer,
+            claim_index,
+            amount,
+            wallet_cluster,
+            faucet.claim_log,
+        );
+
+        self.send(&[instruction], claimer_signer.as_slice())?;
+
+        if let Some(claim_log) = faucet.claim_log {
+            return Ok(claim_log);
+        }
+
+        Ok(pda::claim_record(
+            claimer,
+            &self.faucet_registry(),
+            claim_index,
+        ))
+    }
+
+    /// Records `(claimer, amount)` claims in one transaction. Batched claims get no claim
+    /// record; in `SkipIneligible` mode, skipped claims only show up as `ClaimRejected` events.
+    pub fn record_claims_batch(
+        &mut self,
+        claims: &[(Pubkey, u64)],
+        mode: BatchMode,
+    ) -> Result<Signature> {
+        let claims = claims
+            .iter()
+            .map(|&(claimer, amount)| {
+                let wallet_cluster = self
+                    .get_cluster_membership(&claimer)?
+                    .map(|membership| membership.cluster);
+
+                Ok((claimer, amount, wallet_cluster))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let instruction = instructions::record_claims_batch(
+            &self.payer.pubkey(),
+            &self.faucet_id,
+            &claims,
+            mode,
+            self.get_faucet_stats()?.claim_log,
+        );
+
+        self.send(&[instruction], &[])
+    }
+
+    /// Whether `claimer` could claim `amount` right now, and how long until they can. Only
+    /// simulates, so nothing is written or paid for.
+    pub fn check_eligibility(&mut self, claimer: &Pubkey, amount: u64) -> Result<Eligibility> {
+        let wallet_cluster = self
+            .get_cluster_membership(claimer)?
+            .map(|membership| membership.cluster);
+        let instruction =
+            instructions::check_eligibility(&self.faucet_id, claimer, amount, wallet_cluster);
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            self.connection.latest_blockhash()?,
+        );
+
+        let return_data = self
+            .connection
+            .simulate_transaction(&transaction)?
+            .ok_or(ClientError::MissingReturnData)?;
+
+        Eligibility::try_from_slice(&return_data).map_err(|_| ClientError::MissingReturnData)
+    }
+
+    /// Allocates a claim log and attaches it to the faucet; returns its address. From then
+    /// on claims are appended to the log instead of creating claim records.
+    pub fn create_claim_log(&mut self) -> Result<Pubkey> {
+        let claim_log = Keypair::new();
+        let lamports = self
+            .connection
+            .minimum_balance_for_rent_exemption(ClaimLog::SPACE)?;
+        let create_account = system_instruction::create_account(
+            &self.payer.pubkey(),
+            &claim_log.pubkey(),
+            lamports,
+            ClaimLog::SPACE as u64,
+            &tap_shield::ID,
+        );
+        let init_claim_log = instructions::init_claim_log(
+            &self.payer.pubkey(),
+            &self.faucet_id,
+            &claim_log.pubkey(),
+        );
+
+        self.send(&[create_account, init_claim_log], &[&claim_log])?;
+
+        Ok(claim_log.pubkey())
+    }
+
+    /// Detaches and closes the faucet's claim log, returning its rent to the owner.
+    pub fn close_claim_log(&mut self) -> Result<Signature> {
+        let address = self
+            .get_faucet_stats()?
+            .claim_log
+            .ok_or(ClientError::AccountNotFound(self.faucet_registry()))?;
+        let instruction =
+            instructions::close_claim_log(&self.payer.pubkey(), &self.faucet_id, &address);
+
+        self.send(&[instruction], &[])
+    }
+
+    /// The faucet's claim log, if it has one. Boxed, as the log is too large for the stack.
+    pub fn get_claim_log(&self) -> Result<Option<Box<ClaimLog>>> {
+        let Some(address) = self.get_faucet_stats()?.claim_log else {
+            return Ok(None);
+        };
+
+        let data = self
+            .connection
+            .get_account_data(&address)?
+            .ok_or(ClientError::AccountNotFound(address))?;
+        let body = data
+            .strip_prefix(ClaimLog::DISCRIMINATOR)
+            .filter(|body| body.len() == size_of::<ClaimLog>())
+            .ok_or(ClientError::AccountDidNotDeserialize(address))?;
+
+        let mut claim_log: Box<ClaimLog> = bytemuck::zeroed_box();
+        bytemuck::bytes_of_mut(&mut *claim_log).copy_from_slice(body);
+
+        Ok(Some(claim_log))
+    }
+
+    /// Open claim records of `claimer` across all faucets.
+    pub fn get_user_history(&self, claimer: &Pubkey) -> Result<Vec<(Pubkey, ClaimRecord)>> {
+        let filters = [
+            (0, ClaimRecord::DISCRIMINATOR.to_vec()),
+            (CLAIM_RECORD_CLAIMER_OFFSET, claimer.to_bytes().to_vec()),
+        ];
+
+        self.connection
+            .get_program_accounts(&filters)?
+            .into_iter()
+            .map(|(address, data)| Ok((address, deserialize(&address, &data)?)))
+            .collect()
+    }
+
+    pub fn get_faucet_stats(&self) -> Result<FaucetRegistry> {
+        let address = self.faucet_registry();
+
+        self.fetch(&address)?
+            .ok_or(ClientError::AccountNotFound(address))
+    }
+
+    pub fn get_user_claim_registry(&self, user: &Pubkey) -> Result<Option<UserClaimRegistry>> {
+        self.fetch(&pda::user_claim_registry(user))
+    }
+
+    pub fn get_user_faucet_state(&self, user: &Pubkey) -> Result<Option<UserFaucetState>> {
+        self.fetch(&pda::user_faucet_state(user, &self.faucet_registry()))
+    }
+
+    pub fn get_cluster_membership(&self, wallet: &Pubkey) -> Result<Option<ClusterMembership>> {
+        self.fetch(&pda::cluster_membership(&self.faucet_registry(), wallet))
+    }
+
+    pub fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<Option<T>> {
+        self.connection
+            .get_account_data(address)?
+            .filter(|data| !data.is_empty())
+            .map(|data| deserialize(address, &data))
+            .transpose()
+    }
+
+    /// Sends `instructions` with the client keypair as fee payer plus any extra `signers`.
+    pub fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Signature> {
+        let blockhash = self.connection.latest_blockhash()?;
+
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        self.connection.send_transaction(&transaction)
+    }
+}
+
+fn deserialize<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|_| ClientError::AccountDidNotDeserialize(*address))
+}
@@ -0,0 +1,69 @@
+//! PDA derivations, mirroring the seeds used by the program.
+
+use anchor_lang::prelude::Pubkey;
+
+pub fn faucet_registry(faucet_id: &Pubkey) -> Pubkey {
+    find(&[b"faucet", faucet_id.as_ref()])
+}
+
+pub fn vault(faucet_registry: &Pubkey) -> Pubkey {
+    find(&[b"vault", faucet_registry.as_ref()])
+}
+
+pub fn protocol_config() -> Pubkey {
+    find(&[b"config"])
+}
+
+pub fn claim_record(claimer: &Pubkey, faucet_registry: &Pubkey, claim_index: u64) -> Pubkey {
+    find(&[
+        b"claim",
+        claimer.as_ref(),
+        faucet_registry.as_ref(),
+        &claim_index.to_le_bytes(),
+    ])
+}
+
+pub fn user_claim_registry(user: &Pubkey) -> Pubkey {
+    find(&[b"user_registry", user.as_ref()])
+}
+
+pub fn user_faucet_state(user: &Pubkey, faucet_registry: &Pubkey) -> Pubkey {
+    find(&[b"user_faucet", user.as_ref(), faucet_registry.as_ref()])
+}
+
+pub fn protocol_block_entry(wallet: &Pubkey) -> Pubkey {
+    find(&[b"block", wallet.as_ref()])
+}
+
+pub fn faucet_list_entry(faucet_registry: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    find(&[b"list", faucet_registry.as_ref(), wallet.as_ref()])
+}
+
+pub fn claimer_bond(claimer: &Pubkey) -> Pubkey {
+    find(&[b"bond", claimer.as_ref()])
+}
+
+pub fn slash_report(faucet_registry: &Pubkey, claimer: &Pubkey) -> Pubkey {
+    find(&[b"slash", faucet_registry.as_ref(), claimer.as_ref()])
+}
+
+pub fn wallet_cluster(faucet_registry: &Pubkey, cluster_id: &Pubkey) -> Pubkey {
+    find(&[b"cluster", faucet_registry.as_ref(), cluster_id.as_ref()])
+}
+
+pub fn cluster_membership(faucet_registry: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    find(&[b"cluster_member", faucet_registry.as_ref(), wallet.as_ref()])
+}
+
+/// The program's `ProgramData` account, owned by the upgradeable loader.
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(
+        &[tap_shield::ID.as_ref()],
+        &anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+    )
+    .0
+}
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &tap_shield::ID).0
+}
@@ -10,7 +10,6 @@ import {
   Transaction,
 } from '@solana/web3.js';
 
-const COOLDOWN_SECONDS = 86400;
 const CLAIM_AMOUNT = 0.1 * LAMPORTS_PER_SOL;
 
 const SECRET_KEY = process.env.FAUCET_PVT_KEY;
@@ -54,7 +53,7 @@ export async function POST(req: NextRequest) {
       faucetKeyPair,
     ]);
 
-    await tapshield.recordClaim(claimerPubKey, CLAIM_AMOUNT, COOLDOWN_SECONDS);
+    await tapshield.recordClaim(claimerPubKey, CLAIM_AMOUNT);
 
     return NextResponse.json({
       success: true,
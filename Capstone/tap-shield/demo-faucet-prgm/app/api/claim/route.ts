@@ -10,7 +10,6 @@ import {
   Transaction,
 } from '@solana/web3.js';
 
-const COOLDOWN_SECONDS = 86400;
 const CLAIM_AMOUNT = 2 * LAMPORTS_PER_SOL;
 
 const SECRET_KEY = process.env.FAUCET_PVT_KEY;
@@ -54,7 +53,7 @@ export async function POST(req: NextRequest) {
     //   faucetKeyPair,
     // ]);
 
-    // await tapshield.recordClaim(claimerPubKey, CLAIM_AMOUNT, COOLDOWN_SECONDS);
+    // await tapshield.recordClaim(claimerPubKey, CLAIM_AMOUNT);
 
     // return NextResponse.json({
     //   success: true,
@@ -64,7 +63,7 @@ export async function POST(req: NextRequest) {
     // });
 
     try {
-      await tapshield.recordClaim(claimerPubKey, CLAIM_AMOUNT, COOLDOWN_SECONDS)
+      await tapshield.recordClaim(claimerPubKey, CLAIM_AMOUNT)
     } catch (cooldownErr: any) {
       console.error('Cooldown check failed:', cooldownErr.message);
 
@@ -11,6 +11,7 @@ dotenv.config({ path: resolve(__dirname, "../.env") })
 
 const SECRET_KEY = process.env.FAUCET_PVT_KEY
 const RPC_URL = process.env.RPC_URL || "https://api.devnet.solana.com"
+const COOLDOWN_SECONDS = 86400
 
 if (!SECRET_KEY) {
     throw new Error("FAUCET_PVT_KEY is missing from env")
@@ -24,7 +25,7 @@ async function main() {
     const tapShield = new TapShield(faucetKeyPair, RPC_URL)
 
     try {
-        await tapShield.registerFaucet("Tapshield Demo Faucet")
+        await tapShield.registerFaucet("Tapshield Demo Faucet", COOLDOWN_SECONDS)
 
         console.log("\nFaucet Details!");
         const stats = await tapShield.getFaucetStats();
@@ -0,0 +1,2 @@
+//! Empty on purpose: the integration tests live in `tests/`. This crate is its own
+//! workspace so that the crates in `patches/` only apply to them.
@@ -0,0 +1,2753 @@
+//! In-process integration tests: the program runs natively inside `solana-program-test`, so
+//! `cargo test` needs neither a validator nor the SBF toolchain.
+
+use std::{cell::RefCell, collections::BTreeSet};
+
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+    system_program, AccountSerialize, AnchorDeserialize, Discriminator, InstructionData, Space,
+    ToAccountMetas,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    hash::Hash,
+    program,
+    rent::Rent,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+use tap_shield::instructions::CPI_AUTHORITY_SEED;
+use tap_shield_client::{
+    instructions, pda, ClientError, Connection, Result, TapShieldClient, TapShieldErr,
+};
+use tokio::runtime::Runtime;
+
+const COOLDOWN_SECONDS: i64 = 60;
+const START_TIME: i64 = 1_700_000_000;
+/// Native stand-ins for a faucet's own program, see `caller_process_instruction`.
+const CALLER_PROGRAM: Pubkey = Pubkey::new_from_array([7; 32]);
+const OTHER_CALLER_PROGRAM: Pubkey = Pubkey::new_from_array([8; 32]);
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Anchor's entrypoint wants the account slice to outlive the accounts it deserializes.
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    tap_shield::entry(program_id, accounts, data)
+}
+
+/// A faucet's own program: forwards its instruction data to `check_and_record`, signing with
+/// its `[CPI_AUTHORITY_SEED]` PDA. Takes TapShield's program account, then the
+/// `check_and_record` accounts.
+fn caller_process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let (authority, bump) = Pubkey::find_program_address(&[CPI_AUTHORITY_SEED], program_id);
+
+    let check_and_record = Instruction {
+        program_id: tap_shield::ID,
+        accounts: accounts[1..]
+            .iter()
+            .map(|info| AccountMeta {
+                pubkey: *info.key,
+                is_signer: info.is_signer || *info.key == authority,
+                is_writable: info.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+
+    invoke_signed(
+        &check_and_record,
+        accounts,
+        &[&[CPI_AUTHORITY_SEED, &[bump]]],
+    )
+}
+
+/// `Connection` over a `solana-program-test` bank. Banks has no `getProgramAccounts`, so
+/// every account written by a sent transaction is remembered and scanned instead.
+struct TestValidator {
+    runtime: Runtime,
+    context: ProgramTestContext,
+    touched: RefCell<BTreeSet<Pubkey>>,
+}
+
+impl TestValidator {
+    fn start() -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        // Route the program's `solana_cpi` calls, among them Anchor's return data, to the
+        // `solana-program-test` syscall stubs.
+        solana_cpi::set_stubs(solana_cpi::Stubs {
+            invoke_signed: program::invoke_signed,
+            set_return_data: program::set_return_data,
+            get_return_data: program::get_return_data,
+        });
+
+        let mut program_test = ProgramTest::new(
+            "tap_shield",
+            tap_shield::ID,
+            processor!(process_instruction),
+        );
+        program_test.prefer_bpf(false);
+        for caller in [CALLER_PROGRAM, OTHER_CALLER_PROGRAM] {
+            program_test.add_program("caller", caller, processor!(caller_process_instruction));
+        }
+
+        let mut context = runtime.block_on(program_test.start_with_context());
+
+        // Natively the program has no `ProgramData`; fake one with the payer as upgrade
+        // authority so it can initialize the protocol config.
+        let program_data = AccountSharedData::new_data(
+            1_000_000_000,
+            &UpgradeableLoaderState::ProgramData {
+                slot: 0,
+                upgrade_authority_address: Some(context.payer.pubkey()),
+            },
+            &bpf_loader_upgradeable::ID,
+        )
+        .unwrap();
+        context.set_account(&pda::program_data(), &program_data);
+
+        let mut validator = Self {
+            runtime,
+            context,
+            touched: RefCell::new(BTreeSet::new()),
+        };
+        validator.warp_to(START_TIME);
+        validator
+    }
+
+    fn payer(&self) -> Keypair {
+        self.context.payer.insecure_clone()
+    }
+
+    fn warp_to(&mut self, unix_timestamp: i64) {
+        let clock = self
+            .runtime
+            .block_on(self.context.banks_client.get_sysvar::<Clock>())
+            .unwrap();
+
+        self.context.set_sysvar(&Clock {
+            unix_timestamp,
+            ..clock
+        });
+    }
+
+    fn fund(&mut self, address: &Pubkey, lamports: u64) {
+        let account = AccountSharedData::new(lamports, 0, &system_program::ID);
+        self.context.set_account(address, &account);
+    }
+
+    /// Writes a rent-exempt program-owned account, e.g. one in an older layout.
+    fn set_program_account(&mut self, address: &Pubkey, data: &[u8]) {
+        let mut account = AccountSharedData::new(
+            Rent::default().minimum_balance(data.len()),
+            data.len(),
+            &tap_shield::ID,
+        );
+        account.set_data_from_slice(data);
+        self.context.set_account(address, &account);
+    }
+
+    fn lamports(&self, address: &Pubkey) -> u64 {
+        self.runtime
+            .block_on(self.context.banks_client.get_balance(*address))
+            .unwrap()
+    }
+
+    fn now(&self) -> i64 {
+        self.runtime
+            .block_on(self.context.banks_client.get_sysvar::<Clock>())
+            .unwrap()
+            .unix_timestamp
+    }
+}
+
+impl Connection for TestValidator {
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let account = self
+            .runtime
+            .block_on(self.context.banks_client.get_account(*address))
+            .map_err(map_err)?;
+
+        Ok(account.map(|account| account.data))
+    }
+
+    fn get_program_accounts(&self, filters: &[(usize, Vec<u8>)]) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let mut accounts = Vec::new();
+
+        for address in self.touched.borrow().iter() {
+            let Some(account) = self
+                .runtime
+                .block_on(self.context.banks_client.get_account(*address))
+                .map_err(map_err)?
+            else {
+                continue;
+            };
+
+            let matches = account.owner == tap_shield::ID
+                && filters.iter().all(|(offset, bytes)| {
+                    account.data.get(*offset..offset + bytes.len()) == Some(&bytes[..])
+                });
+
+            if matches {
+                accounts.push((*address, account.data));
+            }
+        }
+
+        Ok(accounts)
+    }
+
+    fn minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64> {
+        let rent = self
+            .runtime
+            .block_on(self.context.banks_client.get_rent())
+            .map_err(map_err)?;
+
+        Ok(rent.minimum_balance(data_len))
+    }
+
+    fn latest_blockhash(&mut self) -> Result<Hash> {
+        // A fresh blockhash keeps otherwise identical retries from being deduplicated.
+        let blockhash = self
+            .runtime
+            .block_on(self.context.get_new_latest_blockhash())
+            .map_err(|error| ClientError::Connection(error.to_string()))?;
+        self.context.last_blockhash = blockhash;
+
+        Ok(blockhash)
+    }
+
+    fn send_transaction(&mut self, transaction: &Transaction) -> Result<Signature> {
+        self.touched
+            .borrow_mut()
+            .extend(transaction.message.account_keys.iter().copied());
+
+        self.runtime
+            .block_on(
+                self.context
+                    .banks_client
+                    .process_transaction(transaction.clone()),
+            )
+            .map_err(map_err)?;
+
+        Ok(transaction.signatures[0])
+    }
+
+    fn simulate_transaction(&self, transaction: &Transaction) -> Result<Option<Vec<u8>>> {
+        let simulation = self
+            .runtime
+            .block_on(
+                self.context
+                    .banks_client
+                    .simulate_transaction(transaction.clone()),
+            )
+            .map_err(map_err)?;
+
+        if let Some(Err(error)) = simulation.result {
+            return Err(error.into());
+        }
+
+        Ok(simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .map(|return_data| return_data.data))
+    }
+}
+
+fn map_err(error: BanksClientError) -> ClientError {
+    match error {
+        BanksClientError::TransactionError(error)
+        | BanksClientError::SimulationError { err: error, .. } => error.into(),
+        error => ClientError::Connection(error.to_string()),
+    }
+}
+
+/// A started validator with the protocol config initialized and no faucet yet.
+fn setup() -> TapShieldClient<TestValidator> {
+    let validator = TestValidator::start();
+    let payer = validator.payer();
+    let mut client = TapShieldClient::new(validator, payer);
+
+    let instruction = instructions::initialize_config(&client.payer().pubkey(), 0);
+    client.send(&[instruction], &[]).unwrap();
+
+    client
+}
+
+/// `setup` plus a faucet registered by the payer.
+fn setup_faucet() -> TapShieldClient<TestValidator> {
+    let mut client = setup();
+    client
+        .register_faucet("Devnet Faucet", COOLDOWN_SECONDS)
+        .unwrap();
+    client
+}
+
+/// For instructions the client has no builder for.
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: tap_shield::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn expect_program_error<T: std::fmt::Debug>(result: Result<T>, expected: TapShieldErr) {
+    match result {
+        Err(error) => assert_eq!(
+            error.program_error().map(u32::from),
+            Some(u32::from(expected)),
+            "{error}"
+        ),
+        Ok(value) => panic!("expected {expected:?}, got Ok({value:?})"),
+    }
+}
+
+#[test]
+fn config_can_only_be_initialized_by_upgrade_authority() {
+    let validator = TestValidator::start();
+    let payer = validator.payer();
+    let mut client = TapShieldClient::new(validator, payer);
+    let impostor = Keypair::new();
+    client
+        .connection_mut()
+        .fund(&impostor.pubkey(), 1_000_000_000);
+
+    expect_program_error(
+        client.send(
+            &[instructions::initialize_config(&impostor.pubkey(), 0)],
+            &[&impostor],
+        ),
+        TapShieldErr::UnauthorizedSigner,
+    );
+
+    let instruction = instructions::initialize_config(&client.payer().pubkey(), 0);
+    client.send(&[instruction], &[]).unwrap();
+
+    let protocol_config: tap_shield::states::ProtocolConfig =
+        client.fetch(&pda::protocol_config()).unwrap().unwrap();
+    assert_eq!(protocol_config.admin, client.payer().pubkey());
+}
+
+#[test]
+fn register_faucet_initializes_registry() {
+    let client = setup_faucet();
+
+    let faucet = client.get_faucet_stats().unwrap();
+    assert_eq!(faucet.faucet_id, client.faucet_id());
+    assert_eq!(faucet.owner, client.payer().pubkey());
+    assert_eq!(faucet.name, "Devnet Faucet");
+    assert_eq!(faucet.cooldown_seconds, COOLDOWN_SECONDS);
+    assert_eq!(faucet.created_at, START_TIME);
+    assert_eq!(faucet.total_claims, 0);
+    assert!(!faucet.paused);
+}
+
+#[test]
+fn first_claim_records_claim_and_registries() {
+    let mut client = setup_faucet();
+    let claimer_keypair = Keypair::new();
+    let claimer = claimer_keypair.pubkey();
+
+    let claim_record = client.record_signed_claim(&claimer_keypair, 1_000).unwrap();
+
+    let record: tap_shield::states::ClaimRecord = client.fetch(&claim_record).unwrap().unwrap();
+    assert_eq!(record.claimer, claimer);
+    assert_eq!(record.faucet_id, client.faucet_registry());
+    assert_eq!(record.amount, 1_000);
+    assert_eq!(record.timestamp, START_TIME);
+
+    let faucet = client.get_faucet_stats().unwrap();
+    assert_eq!(faucet.total_claims, 1);
+    assert_eq!(faucet.total_amount, 1_000);
+    assert_eq!(faucet.unique_claimers, 1);
+
+    let user_registry = client.get_user_claim_registry(&claimer).unwrap().unwrap();
+    let (_, bump) =
+        Pubkey::find_program_address(&[b"user_registry", claimer.as_ref()], &tap_shield::ID);
+    assert_eq!(user_registry.user, claimer);
+    assert_eq!(user_registry.total_claims_across_faucets, 1);
+    assert_eq!(user_registry.last_claim_timestamp, START_TIME);
+    assert_eq!(user_registry.bump, bump);
+
+    let user_faucet_state = client.get_user_faucet_state(&claimer).unwrap().unwrap();
+    assert_eq!(user_faucet_state.faucet, client.faucet_registry());
+    assert_eq!(user_faucet_state.first_claim_timestamp, START_TIME);
+    assert_eq!(user_faucet_state.claim_count, 1);
+    assert_eq!(user_faucet_state.total_amount, 1_000);
+}
+
+#[test]
+fn unsigned_claims_leave_global_registry_untouched() {
+    let mut client = setup_faucet();
+    let claimer = Keypair::new();
+
+    client.record_signed_claim(&claimer, 100).unwrap();
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    client.record_claim(&claimer.pubkey(), 100).unwrap();
+
+    let user_registry = client
+        .get_user_claim_registry(&claimer.pubkey())
+        .unwrap()
+        .unwrap();
+    assert_eq!(user_registry.total_claims_across_faucets, 1);
+    assert_eq!(user_registry.last_claim_timestamp, START_TIME);
+
+    let user_faucet_state = client
+        .get_user_faucet_state(&claimer.pubkey())
+        .unwrap()
+        .unwrap();
+    assert_eq!(user_faucet_state.claim_count, 2);
+
+    let other = Pubkey::new_unique();
+    client.record_claim(&other, 100).unwrap();
+    assert!(client.get_user_claim_registry(&other).unwrap().is_none());
+}
+
+#[test]
+fn unsigned_claims_update_global_registry_at_approved_faucets() {
+    use tap_shield::instructions::BatchMode;
+
+    let mut client = setup_faucet();
+    let admin = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let claimer = Pubkey::new_unique();
+    let batched = Pubkey::new_unique();
+
+    let set_registry_approval = |approved: bool| {
+        instruction(
+            tap_shield::accounts::SetRegistryApproval {
+                admin,
+                protocol_config: pda::protocol_config(),
+                faucet_registry,
+            },
+            tap_shield::instruction::SetRegistryApproval { approved },
+        )
+    };
+
+    let intruder = Keypair::new();
+    client
+        .connection_mut()
+        .fund(&intruder.pubkey(), 1_000_000_000);
+    let mut forged = set_registry_approval(true);
+    forged.accounts[0].pubkey = intruder.pubkey();
+    expect_program_error(
+        client.send(&[forged], &[&intruder]),
+        TapShieldErr::UnauthorizedSigner,
+    );
+
+    client.send(&[set_registry_approval(true)], &[]).unwrap();
+    assert!(client.get_faucet_stats().unwrap().registry_approved);
+
+    client.record_claim(&claimer, 100).unwrap();
+    client
+        .record_claims_batch(&[(batched, 100)], BatchMode::Atomic)
+        .unwrap();
+
+    for user in [claimer, batched] {
+        let user_registry = client.get_user_claim_registry(&user).unwrap().unwrap();
+        assert_eq!(user_registry.total_claims_across_faucets, 1);
+        assert_eq!(user_registry.last_claim_timestamp, START_TIME);
+        assert_eq!(user_registry.last_faucet, faucet_registry);
+    }
+
+    client.send(&[set_registry_approval(false)], &[]).unwrap();
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    client.record_claim(&claimer, 100).unwrap();
+
+    let user_registry = client.get_user_claim_registry(&claimer).unwrap().unwrap();
+    assert_eq!(user_registry.total_claims_across_faucets, 1);
+}
+
+#[test]
+fn global_cooldown_spans_faucets_unless_overridden() {
+    const GLOBAL_COOLDOWN_SECONDS: i64 = 10 * COOLDOWN_SECONDS;
+
+    let mut client = setup_faucet();
+    let admin = client.payer().pubkey();
+    let claimer = Keypair::new();
+    let faucet_a = client.faucet_id();
+    let faucet_b = Pubkey::new_unique();
+
+    let update_config = |global_cooldown_seconds: i64| {
+        instruction(
+            tap_shield::accounts::UpdateConfig {
+                admin,
+                protocol_config: pda::protocol_config(),
+            },
+            tap_shield::instruction::UpdateConfig {
+                global_cooldown_seconds,
+            },
+        )
+    };
+    let set_faucet_override = |faucet_id: &Pubkey, global_cooldown_override: Option<i64>| {
+        instruction(
+            tap_shield::accounts::SetFaucetOverride {
+                admin,
+                protocol_config: pda::protocol_config(),
+                faucet_registry: pda::faucet_registry(faucet_id),
+            },
+            tap_shield::instruction::SetFaucetOverride {
+                global_cooldown_override,
+            },
+        )
+    };
+
+    let intruder = Keypair::new();
+    client
+        .connection_mut()
+        .fund(&intruder.pubkey(), 1_000_000_000);
+    let mut forged = update_config(0);
+    forged.accounts[0].pubkey = intruder.pubkey();
+    expect_program_error(
+        client.send(&[forged], &[&intruder]),
+        TapShieldErr::UnauthorizedSigner,
+    );
+    expect_program_error(
+        client.send(&[update_config(-1)], &[]),
+        TapShieldErr::InvalidCooldown,
+    );
+    client
+        .send(&[update_config(GLOBAL_COOLDOWN_SECONDS)], &[])
+        .unwrap();
+
+    let mut client = client.with_faucet_id(faucet_b);
+    client
+        .register_faucet("Other Faucet", COOLDOWN_SECONDS)
+        .unwrap();
+
+    let mut client = client.with_faucet_id(faucet_a);
+    client.record_signed_claim(&claimer, 100).unwrap();
+
+    // Faucet B's own cooldown has passed, but the network-wide one hasn't.
+    let mut client = client.with_faucet_id(faucet_b);
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    expect_program_error(
+        client.record_signed_claim(&claimer, 100),
+        TapShieldErr::GlobalCooldownActive,
+    );
+
+    client
+        .send(
+            &[set_faucet_override(&faucet_b, Some(COOLDOWN_SECONDS))],
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        client.get_faucet_stats().unwrap().global_cooldown_override,
+        Some(COOLDOWN_SECONDS)
+    );
+    client.record_signed_claim(&claimer, 100).unwrap();
+
+    // The claim at faucet B restarted the window that faucet A still enforces.
+    let mut client = client.with_faucet_id(faucet_a);
+    client
+        .connection_mut()
+        .warp_to(START_TIME + GLOBAL_COOLDOWN_SECONDS);
+    expect_program_error(
+        client.record_signed_claim(&claimer, 100),
+        TapShieldErr::GlobalCooldownActive,
+    );
+
+    client.send(&[update_config(0)], &[]).unwrap();
+    client.record_signed_claim(&claimer, 100).unwrap();
+
+    let user_registry = client
+        .get_user_claim_registry(&claimer.pubkey())
+        .unwrap()
+        .unwrap();
+    assert_eq!(user_registry.total_claims_across_faucets, 3);
+    assert_eq!(user_registry.last_faucet, pda::faucet_registry(&faucet_a));
+
+    // `None` puts faucet B back on the network-wide cooldown.
+    client
+        .send(&[set_faucet_override(&faucet_b, None)], &[])
+        .unwrap();
+    let client = client.with_faucet_id(faucet_b);
+    assert_eq!(
+        client.get_faucet_stats().unwrap().global_cooldown_override,
+        None
+    );
+}
+
+#[test]
+fn claim_within_cooldown_is_rejected_until_it_elapses() {
+    let mut client = setup_faucet();
+    let claimer = Pubkey::new_unique();
+
+    client.record_claim(&claimer, 1_000).unwrap();
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS - 1);
+    expect_program_error(
+        client.record_claim(&claimer, 1_000),
+        TapShieldErr::ClaimTooRecent,
+    );
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    client.record_claim(&claimer, 1_000).unwrap();
+
+    let user_faucet_state = client.get_user_faucet_state(&claimer).unwrap().unwrap();
+    assert_eq!(user_faucet_state.claim_count, 2);
+    assert_eq!(
+        user_faucet_state.last_claim_timestamp,
+        client.connection().now()
+    );
+    assert_eq!(client.get_faucet_stats().unwrap().total_claims, 2);
+}
+
+#[test]
+fn claim_from_unknown_operator_is_rejected() {
+    let mut client = setup_faucet();
+    let operator = Keypair::new();
+    let claimer = Pubkey::new_unique();
+    client
+        .connection_mut()
+        .fund(&operator.pubkey(), 1_000_000_000);
+
+    let instruction = instructions::record_claim(
+        &operator.pubkey(),
+        &client.faucet_id(),
+        &claimer,
+        0,
+        1_000,
+        None,
+        None,
+    );
+
+    expect_program_error(
+        client.send(&[instruction], &[&operator]),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+    assert_eq!(client.get_faucet_stats().unwrap().total_claims, 0);
+}
+
+#[test]
+fn claim_with_mismatched_claimer_pubkey_is_rejected() {
+    let mut client = setup_faucet();
+    let claimer = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+
+    let mut instruction = instructions::record_claim(
+        &client.payer().pubkey(),
+        &client.faucet_id(),
+        &claimer,
+        0,
+        1_000,
+        None,
+        None,
+    );
+    instruction.data = tap_shield::instruction::RecordClaim {
+        claimer_pubkey: other,
+        amount: 1_000,
+    }
+    .data();
+    // The user registry is seeded by the instruction argument, not the claimer account.
+    let user_claim_registry = pda::user_claim_registry(&claimer);
+    instruction
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == user_claim_registry)
+        .unwrap()
+        .pubkey = pda::user_claim_registry(&other);
+
+    expect_program_error(
+        client.send(&[instruction], &[]),
+        TapShieldErr::InvalidClaimer,
+    );
+    assert!(client.get_user_claim_registry(&claimer).unwrap().is_none());
+    assert!(client.get_user_claim_registry(&other).unwrap().is_none());
+}
+
+#[test]
+fn faucet_name_length_is_enforced() {
+    let mut client = setup();
+
+    let result = client.register_faucet(&"a".repeat(33), COOLDOWN_SECONDS);
+    expect_program_error(result, TapShieldErr::FaucetNameTooLong);
+
+    let result = client.register_faucet("", COOLDOWN_SECONDS);
+    expect_program_error(result, TapShieldErr::InvalidInput);
+
+    client
+        .register_faucet(&"a".repeat(32), COOLDOWN_SECONDS)
+        .unwrap();
+    assert_eq!(client.get_faucet_stats().unwrap().name, "a".repeat(32));
+}
+
+#[test]
+fn counters_track_claims_across_claimers() {
+    let mut client = setup_faucet();
+    let alice_keypair = Keypair::new();
+    let alice = alice_keypair.pubkey();
+    let bob = Pubkey::new_unique();
+
+    client.record_signed_claim(&alice_keypair, 100).unwrap();
+    client.record_claim(&bob, 200).unwrap();
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    client.record_signed_claim(&alice_keypair, 300).unwrap();
+
+    let faucet = client.get_faucet_stats().unwrap();
+    assert_eq!(faucet.total_claims, 3);
+    assert_eq!(faucet.total_amount, 600);
+    assert_eq!(faucet.unique_claimers, 2);
+
+    let alice_registry = client.get_user_claim_registry(&alice).unwrap().unwrap();
+    assert_eq!(alice_registry.total_claims_across_faucets, 2);
+
+    let bob_state = client.get_user_faucet_state(&bob).unwrap().unwrap();
+    assert_eq!(bob_state.claim_count, 1);
+    assert_eq!(bob_state.total_amount, 200);
+
+    let mut history = client.get_user_history(&alice).unwrap();
+    history.sort_by_key(|(_, record)| record.timestamp);
+    let amounts: Vec<u64> = history.iter().map(|(_, record)| record.amount).collect();
+    assert_eq!(amounts, [100, 300]);
+    assert!(history.iter().all(|(_, record)| record.claimer == alice));
+}
+
+#[test]
+fn quota_window_is_not_shared_between_faucets() {
+    use tap_shield::states::{ClaimQuota, RECENT_CLAIMS_LEN};
+
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let claimer = Pubkey::new_unique();
+
+    let update_faucet_policy = instruction(
+        tap_shield::accounts::UpdateFaucetPolicy {
+            owner,
+            faucet_registry: client.faucet_registry(),
+        },
+        tap_shield::instruction::UpdateFaucetPolicy {
+            cooldown_seconds: COOLDOWN_SECONDS,
+            claim_quota: Some(ClaimQuota {
+                max_claims: 2,
+                max_amount: 0,
+                window_seconds: 86_400,
+            }),
+            require_claimer_signature: false,
+        },
+    );
+    client.send(&[update_faucet_policy], &[]).unwrap();
+
+    let mut now = START_TIME;
+    for _ in 0..2 {
+        client.connection_mut().warp_to(now);
+        client.record_claim(&claimer, 100).unwrap();
+        now += COOLDOWN_SECONDS;
+    }
+    client.connection_mut().warp_to(now);
+    expect_program_error(
+        client.record_claim(&claimer, 100),
+        TapShieldErr::ClaimQuotaExceeded,
+    );
+
+    // A full ring of claims at another faucet doesn't push the first faucet's out.
+    let faucet_id = client.faucet_id();
+    let mut client = client.with_faucet_id(Pubkey::new_unique());
+    client
+        .register_faucet("Other Faucet", COOLDOWN_SECONDS)
+        .unwrap();
+    for _ in 0..RECENT_CLAIMS_LEN {
+        client.connection_mut().warp_to(now);
+        client.record_claim(&claimer, 1).unwrap();
+        now += COOLDOWN_SECONDS;
+    }
+
+    let mut client = client.with_faucet_id(faucet_id);
+    client.connection_mut().warp_to(now);
+    expect_program_error(
+        client.record_claim(&claimer, 100),
+        TapShieldErr::ClaimQuotaExceeded,
+    );
+}
+
+#[test]
+fn vault_pays_claims_and_withdrawals_without_leaving_dust() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let vault = pda::vault(&faucet_registry);
+    let rent_exempt = Rent::default().minimum_balance(0);
+
+    let deposit_vault = |owner: Pubkey, amount: u64| {
+        instruction(
+            tap_shield::accounts::DepositVault {
+                owner,
+                faucet_registry,
+                vault,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::DepositVault { amount },
+        )
+    };
+    let withdraw_vault = |amount: u64| {
+        instruction(
+            tap_shield::accounts::WithdrawVault {
+                owner,
+                faucet_registry,
+                vault,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::WithdrawVault { amount },
+        )
+    };
+    let claim = |claimer: Pubkey, claim_index: u64, amount: u64| {
+        instruction(
+            tap_shield::accounts::Claim {
+                operator: owner,
+                claimer,
+                faucet_registry,
+                protocol_config: pda::protocol_config(),
+                vault,
+                claim_record: Some(pda::claim_record(&claimer, &faucet_registry, claim_index)),
+                user_claim_registry: pda::user_claim_registry(&claimer),
+                user_faucet_state: pda::user_faucet_state(&claimer, &faucet_registry),
+                protocol_block_entry: pda::protocol_block_entry(&claimer),
+                faucet_list_entry: pda::faucet_list_entry(&faucet_registry, &claimer),
+                claimer_bond: pda::claimer_bond(&claimer),
+                cluster_membership: pda::cluster_membership(&faucet_registry, &claimer),
+                wallet_cluster: None,
+                instruction_sysvar: None,
+                system_program: system_program::ID,
+                claim_log: None,
+            },
+            tap_shield::instruction::Claim { amount },
+        )
+    };
+
+    let stranger = Keypair::new();
+    client
+        .connection_mut()
+        .fund(&stranger.pubkey(), 1_000_000_000);
+    expect_program_error(
+        client.send(&[deposit_vault(stranger.pubkey(), 1_000)], &[&stranger]),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+
+    client
+        .send(&[deposit_vault(owner, 10_000_000)], &[])
+        .unwrap();
+    assert_eq!(client.connection().lamports(&vault), 10_000_000);
+
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    client.send(&[claim(alice, 0, 2_000_000)], &[]).unwrap();
+    assert_eq!(client.connection().lamports(&alice), 2_000_000);
+    assert_eq!(client.connection().lamports(&vault), 8_000_000);
+    assert_eq!(client.get_faucet_stats().unwrap().total_amount, 2_000_000);
+
+    // A payout may empty the vault or leave it rent-exempt, but nothing in between.
+    expect_program_error(
+        client.send(&[claim(bob, 1, 8_000_001)], &[]),
+        TapShieldErr::InsufficientVaultBalance,
+    );
+    expect_program_error(
+        client.send(&[claim(bob, 1, 8_000_000 - rent_exempt + 1)], &[]),
+        TapShieldErr::InsufficientVaultBalance,
+    );
+    expect_program_error(
+        client.send(&[withdraw_vault(8_000_000 - rent_exempt + 1)], &[]),
+        TapShieldErr::InsufficientVaultBalance,
+    );
+    assert!(client.get_user_faucet_state(&bob).unwrap().is_none());
+
+    client
+        .send(&[claim(bob, 1, 8_000_000 - rent_exempt)], &[])
+        .unwrap();
+    assert_eq!(client.connection().lamports(&vault), rent_exempt);
+
+    let owner_balance = client.connection().lamports(&owner);
+    client.send(&[withdraw_vault(rent_exempt)], &[]).unwrap();
+    assert_eq!(client.connection().lamports(&vault), 0);
+    assert!(client.connection().lamports(&owner) > owner_balance);
+}
+
+/// Creates a 6-decimal mint owned by `token_program`, with the payer as mint authority.
+fn create_mint(client: &mut TapShieldClient<TestValidator>, token_program: &Pubkey) -> Pubkey {
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token_2022::spl_token_2022::{self, state::Mint};
+
+    let payer = client.payer().pubkey();
+    let mint = Keypair::new();
+    let create_account = anchor_lang::solana_program::system_instruction::create_account(
+        &payer,
+        &mint.pubkey(),
+        Rent::default().minimum_balance(Mint::LEN),
+        Mint::LEN as u64,
+        token_program,
+    );
+    let initialize_mint = spl_token_2022::instruction::initialize_mint2(
+        token_program,
+        &mint.pubkey(),
+        &payer,
+        None,
+        6,
+    )
+    .unwrap();
+    client
+        .send(&[create_account, initialize_mint], &[&mint])
+        .unwrap();
+
+    mint.pubkey()
+}
+
+/// Creates `wallet`'s associated token account for `mint`, minting `amount` into it.
+fn create_token_account(
+    client: &mut TapShieldClient<TestValidator>,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    wallet: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    use anchor_spl::{
+        associated_token::spl_associated_token_account::instruction::create_associated_token_account,
+        token_2022::spl_token_2022,
+    };
+
+    let payer = client.payer().pubkey();
+    let mut instructions = vec![create_associated_token_account(
+        &payer,
+        wallet,
+        mint,
+        token_program,
+    )];
+    let token_account = token_account_address(token_program, mint, wallet);
+    if amount > 0 {
+        instructions.push(
+            spl_token_2022::instruction::mint_to(
+                token_program,
+                mint,
+                &token_account,
+                &payer,
+                &[],
+                amount,
+            )
+            .unwrap(),
+        );
+    }
+    client.send(&instructions, &[]).unwrap();
+
+    token_account
+}
+
+fn token_account_address(token_program: &Pubkey, mint: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+        wallet,
+        mint,
+        token_program,
+    )
+}
+
+fn token_balance(client: &TapShieldClient<TestValidator>, token_account: &Pubkey) -> u64 {
+    client
+        .fetch::<anchor_spl::token_interface::TokenAccount>(token_account)
+        .unwrap()
+        .map_or(0, |account| account.amount)
+}
+
+/// Binds a mint, deposits, pays out a claim with `transfer_checked` and withdraws the rest,
+/// all through `token_program`.
+fn token_faucet_round_trip(token_program: Pubkey) {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let vault = pda::vault(&faucet_registry);
+
+    let mint = create_mint(&mut client, &token_program);
+    let owner_ata = create_token_account(&mut client, &token_program, &mint, &owner, 1_000_000);
+    let vault_ata = token_account_address(&token_program, &mint, &vault);
+
+    // The wrong mint has token accounts too, so only the faucet's binding rejects it.
+    let wrong_mint = create_mint(&mut client, &token_program);
+    create_token_account(&mut client, &token_program, &wrong_mint, &owner, 1_000_000);
+    create_token_account(&mut client, &token_program, &wrong_mint, &vault, 1_000_000);
+
+    let bind_faucet_mint = |mint: Pubkey| {
+        instruction(
+            tap_shield::accounts::BindFaucetMint {
+                owner,
+                faucet_registry,
+                vault,
+                mint,
+                vault_ata: token_account_address(&token_program, &mint, &vault),
+                associated_token_program: anchor_spl::associated_token::ID,
+                token_program,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::BindFaucetMint {},
+        )
+    };
+    let deposit_tokens = |mint: Pubkey, amount: u64| {
+        instruction(
+            tap_shield::accounts::DepositTokens {
+                owner,
+                faucet_registry,
+                vault,
+                mint,
+                owner_ata: token_account_address(&token_program, &mint, &owner),
+                vault_ata: token_account_address(&token_program, &mint, &vault),
+                associated_token_program: anchor_spl::associated_token::ID,
+                token_program,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::DepositTokens { amount },
+        )
+    };
+    let withdraw_tokens = |mint: Pubkey, amount: u64| {
+        instruction(
+            tap_shield::accounts::WithdrawTokens {
+                owner,
+                faucet_registry,
+                vault,
+                mint,
+                owner_ata: token_account_address(&token_program, &mint, &owner),
+                vault_ata: token_account_address(&token_program, &mint, &vault),
+                associated_token_program: anchor_spl::associated_token::ID,
+                token_program,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::WithdrawTokens { amount },
+        )
+    };
+    let claim_tokens = |mint: Pubkey, claimer: Pubkey, claim_index: u64, amount: u64| {
+        instruction(
+            tap_shield::accounts::ClaimTokens {
+                operator: owner,
+                claimer,
+                faucet_registry,
+                protocol_config: pda::protocol_config(),
+                vault,
+                mint,
+                vault_ata: token_account_address(&token_program, &mint, &vault),
+                claimer_ata: token_account_address(&token_program, &mint, &claimer),
+                claim_record: Some(pda::claim_record(&claimer, &faucet_registry, claim_index)),
+                user_claim_registry: pda::user_claim_registry(&claimer),
+                user_faucet_state: pda::user_faucet_state(&claimer, &faucet_registry),
+                protocol_block_entry: pda::protocol_block_entry(&claimer),
+                faucet_list_entry: pda::faucet_list_entry(&faucet_registry, &claimer),
+                claimer_bond: pda::claimer_bond(&claimer),
+                cluster_membership: pda::cluster_membership(&faucet_registry, &claimer),
+                wallet_cluster: None,
+                instruction_sysvar: None,
+                associated_token_program: anchor_spl::associated_token::ID,
+                token_program,
+                system_program: system_program::ID,
+                claim_log: None,
+            },
+            tap_shield::instruction::ClaimTokens { amount },
+        )
+    };
+
+    // Token instructions need a bound mint.
+    expect_program_error(
+        client.send(&[deposit_tokens(wrong_mint, 1)], &[]),
+        TapShieldErr::FaucetMintMismatch,
+    );
+
+    client.send(&[bind_faucet_mint(mint)], &[]).unwrap();
+    assert_eq!(client.get_faucet_stats().unwrap().mint, Some(mint));
+    expect_program_error(
+        client.send(&[bind_faucet_mint(wrong_mint)], &[]),
+        TapShieldErr::FaucetMintAlreadyBound,
+    );
+
+    client.send(&[deposit_tokens(mint, 600_000)], &[]).unwrap();
+    assert_eq!(token_balance(&client, &vault_ata), 600_000);
+    assert_eq!(token_balance(&client, &owner_ata), 400_000);
+
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    client
+        .send(&[claim_tokens(mint, alice, 0, 250_000)], &[])
+        .unwrap();
+    let alice_ata = token_account_address(&token_program, &mint, &alice);
+    assert_eq!(token_balance(&client, &alice_ata), 250_000);
+    assert_eq!(token_balance(&client, &vault_ata), 350_000);
+
+    let faucet = client.get_faucet_stats().unwrap();
+    assert_eq!(faucet.total_claims, 1);
+    assert_eq!(faucet.total_amount, 250_000);
+
+    expect_program_error(
+        client.send(&[claim_tokens(mint, bob, 1, 350_001)], &[]),
+        TapShieldErr::InsufficientVaultBalance,
+    );
+    for wrong_mint_instruction in [
+        deposit_tokens(wrong_mint, 1),
+        claim_tokens(wrong_mint, bob, 1, 1),
+        withdraw_tokens(wrong_mint, 1),
+    ] {
+        expect_program_error(
+            client.send(&[wrong_mint_instruction], &[]),
+            TapShieldErr::FaucetMintMismatch,
+        );
+    }
+
+    expect_program_error(
+        client.send(&[withdraw_tokens(mint, 350_001)], &[]),
+        TapShieldErr::InsufficientVaultBalance,
+    );
+    client.send(&[withdraw_tokens(mint, 350_000)], &[]).unwrap();
+    assert_eq!(token_balance(&client, &vault_ata), 0);
+    assert_eq!(token_balance(&client, &owner_ata), 750_000);
+}
+
+#[test]
+fn spl_token_faucet_claims_and_withdraws() {
+    token_faucet_round_trip(anchor_spl::token::ID);
+}
+
+#[test]
+fn token_2022_faucet_claims_and_withdraws() {
+    token_faucet_round_trip(anchor_spl::token_2022::ID);
+}
+
+#[test]
+fn bonded_faucet_requires_bond_and_slashes_it_into_vault() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let claimer = Keypair::new();
+    let claimer_bond = pda::claimer_bond(&claimer.pubkey());
+    let vault = pda::vault(&faucet_registry);
+    client
+        .connection_mut()
+        .fund(&claimer.pubkey(), 1_000_000_000);
+
+    let set_required_bond = instruction(
+        tap_shield::accounts::SetRequiredBond {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::SetRequiredBond {
+            required_bond: 100_000_000,
+        },
+    );
+    client.send(&[set_required_bond], &[]).unwrap();
+
+    expect_program_error(
+        client.record_signed_claim(&claimer, 1_000),
+        TapShieldErr::BondRequired,
+    );
+
+    let deposit_bond = instruction(
+        tap_shield::accounts::DepositBond {
+            claimer: claimer.pubkey(),
+            claimer_bond,
+            system_program: system_program::ID,
+        },
+        tap_shield::instruction::DepositBond {
+            amount: 100_000_000,
+        },
+    );
+    client.send(&[deposit_bond], &[&claimer]).unwrap();
+    expect_program_error(
+        client.record_claim(&claimer.pubkey(), 1_000),
+        TapShieldErr::ClaimerSignatureRequired,
+    );
+
+    let slash_report = pda::slash_report(&faucet_registry, &claimer.pubkey());
+    let report_abuse = |amount: u64| {
+        instruction(
+            tap_shield::accounts::ReportAbuse {
+                operator: owner,
+                claimer: claimer.pubkey(),
+                faucet_registry,
+                user_faucet_state: pda::user_faucet_state(&claimer.pubkey(), &faucet_registry),
+                claimer_bond,
+                slash_report,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::ReportAbuse {
+                amount,
+                reason: "sybil farm".to_string(),
+            },
+        )
+    };
+    let resolve_slash_report = instruction(
+        tap_shield::accounts::ResolveSlashReport {
+            admin: owner,
+            protocol_config: pda::protocol_config(),
+            faucet_registry,
+            vault,
+            faucet_owner: owner,
+            slash_report,
+            claimer_bond,
+            reporter: owner,
+        },
+        tap_shield::instruction::ResolveSlashReport { approve: true },
+    );
+
+    // A faucet can only report wallets that claimed there.
+    assert!(matches!(
+        client.send(&[report_abuse(500_000)], &[]),
+        Err(ClientError::Custom(code))
+            if code == anchor_lang::error::ErrorCode::AccountNotInitialized as u32
+    ));
+    client.record_signed_claim(&claimer, 1_000).unwrap();
+
+    // Too small to make the empty vault rent-exempt, so it goes to the faucet owner.
+    client.send(&[report_abuse(500_000)], &[]).unwrap();
+
+    let close_faucet = instruction(
+        tap_shield::accounts::CloseFaucet {
+            owner,
+            faucet_registry,
+            vault,
+            vault_ata: None,
+            token_program: None,
+        },
+        tap_shield::instruction::CloseFaucet {},
+    );
+    expect_program_error(
+        client.send(&[close_faucet], &[]),
+        TapShieldErr::FaucetHasPendingReports,
+    );
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + tap_shield::states::BOND_LOCK_SECONDS);
+    let withdraw_bond = instruction(
+        tap_shield::accounts::WithdrawBond {
+            claimer: claimer.pubkey(),
+            claimer_bond,
+            user_claim_registry: pda::user_claim_registry(&claimer.pubkey()),
+        },
+        tap_shield::instruction::WithdrawBond {
+            amount: 100_000_000,
+        },
+    );
+    expect_program_error(
+        client.send(std::slice::from_ref(&withdraw_bond), &[&claimer]),
+        TapShieldErr::BondLocked,
+    );
+
+    client
+        .send(std::slice::from_ref(&resolve_slash_report), &[])
+        .unwrap();
+    assert_eq!(client.connection().lamports(&vault), 0);
+    assert_eq!(client.get_faucet_stats().unwrap().pending_slash_reports, 0);
+
+    client.send(&[report_abuse(60_000_000)], &[]).unwrap();
+    client.send(&[resolve_slash_report], &[]).unwrap();
+
+    let bond: tap_shield::states::ClaimerBond = client.fetch(&claimer_bond).unwrap().unwrap();
+    assert_eq!(bond.amount, 39_500_000);
+    assert_eq!(bond.pending_reports, 0);
+
+    assert_eq!(client.connection().lamports(&vault), 60_000_000);
+    assert!(client
+        .fetch::<tap_shield::states::SlashReport>(&slash_report)
+        .unwrap()
+        .is_none());
+
+    expect_program_error(
+        client.record_signed_claim(&claimer, 1_000),
+        TapShieldErr::BondRequired,
+    );
+
+    expect_program_error(
+        client.send(&[withdraw_bond], &[&claimer]),
+        TapShieldErr::InsufficientBond,
+    );
+
+    // Without a bond requirement there is nothing to slash, so the faucet can't report.
+    let clear_required_bond = instruction(
+        tap_shield::accounts::SetRequiredBond {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::SetRequiredBond { required_bond: 0 },
+    );
+    client.send(&[clear_required_bond], &[]).unwrap();
+    expect_program_error(
+        client.send(&[report_abuse(1_000)], &[]),
+        TapShieldErr::BondNotRequired,
+    );
+}
+
+#[test]
+fn linked_wallets_share_cluster_cooldown() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let funder = Pubkey::new_unique();
+    let wallet_cluster = pda::wallet_cluster(&faucet_registry, &funder);
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    client.record_claim(&alice, 100).unwrap();
+
+    for wallet in [alice, bob] {
+        let link_wallet = instruction(
+            tap_shield::accounts::LinkWallet {
+                authority: owner,
+                faucet_registry,
+                wallet_cluster,
+                cluster_membership: pda::cluster_membership(&faucet_registry, &wallet),
+                user_faucet_state: pda::user_faucet_state(&wallet, &faucet_registry),
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::LinkWallet {
+                cluster_id: funder,
+                wallet,
+            },
+        );
+        client.send(&[link_wallet], &[]).unwrap();
+    }
+
+    let cluster: tap_shield::states::WalletCluster =
+        client.fetch(&wallet_cluster).unwrap().unwrap();
+    assert_eq!(cluster.member_count, 2);
+    assert_eq!(cluster.last_claim_timestamp, Some(START_TIME));
+
+    // Bob never claimed, but Alice's claim put the whole cluster on cooldown.
+    expect_program_error(
+        client.record_claim(&bob, 100),
+        TapShieldErr::ClusterCooldownActive,
+    );
+
+    // A linked claimer cannot dodge the check by leaving out their cluster.
+    let claim_index = client.get_faucet_stats().unwrap().total_claims;
+    let without_cluster = instructions::record_claim(
+        &owner,
+        &client.faucet_id(),
+        &bob,
+        claim_index,
+        100,
+        None,
+        None,
+    );
+    expect_program_error(
+        client.send(&[without_cluster], &[]),
+        TapShieldErr::ClusterMismatch,
+    );
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    client.record_claim(&bob, 100).unwrap();
+
+    expect_program_error(
+        client.record_claim(&alice, 100),
+        TapShieldErr::ClusterCooldownActive,
+    );
+
+    let cluster: tap_shield::states::WalletCluster =
+        client.fetch(&wallet_cluster).unwrap().unwrap();
+    assert_eq!(cluster.total_claims, 1);
+    assert_eq!(
+        cluster.last_claim_timestamp,
+        Some(START_TIME + COOLDOWN_SECONDS)
+    );
+
+    // Someone else's cluster is rejected, not used to compute the wait.
+    let outsider = Pubkey::new_unique();
+    let check_eligibility =
+        instructions::check_eligibility(&client.faucet_id(), &outsider, 100, Some(wallet_cluster));
+    let blockhash = client.connection_mut().latest_blockhash().unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[check_eligibility],
+        Some(&owner),
+        &[client.payer()],
+        blockhash,
+    );
+    let return_data = client
+        .connection()
+        .simulate_transaction(&transaction)
+        .unwrap()
+        .unwrap();
+    let eligibility = tap_shield::instructions::Eligibility::try_from_slice(&return_data).unwrap();
+    assert_eq!(
+        eligibility.error_code,
+        Some(u32::from(TapShieldErr::ClusterMismatch))
+    );
+    assert_eq!(eligibility.seconds_remaining, 0);
+
+    // Unlinking the last member closes the cluster.
+    for (wallet, members_left) in [(alice, Some(1)), (bob, None)] {
+        let unlink_wallet = instruction(
+            tap_shield::accounts::UnlinkWallet {
+                authority: owner,
+                faucet_registry,
+                cluster_membership: pda::cluster_membership(&faucet_registry, &wallet),
+                wallet_cluster,
+            },
+            tap_shield::instruction::UnlinkWallet {},
+        );
+        client.send(&[unlink_wallet], &[]).unwrap();
+
+        let cluster = client
+            .fetch::<tap_shield::states::WalletCluster>(&wallet_cluster)
+            .unwrap();
+        assert_eq!(cluster.map(|cluster| cluster.member_count), members_left);
+    }
+}
+
+#[test]
+fn reputation_scales_claims_and_drops_on_flags() {
+    use tap_shield::states::{
+        ReputationFlag, ReputationPolicy, INITIAL_REPUTATION, MAX_FLAGS_PER_FAUCET,
+    };
+
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let claimer_keypair = Keypair::new();
+    let claimer = claimer_keypair.pubkey();
+
+    let set_reputation_policy = instruction(
+        tap_shield::accounts::SetReputationPolicy {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::SetReputationPolicy {
+            reputation_policy: Some(ReputationPolicy {
+                min_score: 450,
+                max_amount: 1_000,
+            }),
+        },
+    );
+    client.send(&[set_reputation_policy], &[]).unwrap();
+
+    // A fresh wallet starts at half the maximum score, so it may claim half of `max_amount`.
+    expect_program_error(
+        client.record_signed_claim(&claimer_keypair, 501),
+        TapShieldErr::ReputationAmountExceeded,
+    );
+    client.record_signed_claim(&claimer_keypair, 500).unwrap();
+
+    let flag_claimer = |claimer: Pubkey| {
+        instruction(
+            tap_shield::accounts::FlagClaimer {
+                operator: owner,
+                claimer,
+                faucet_registry,
+                user_faucet_state: pda::user_faucet_state(&claimer, &faucet_registry),
+                user_claim_registry: pda::user_claim_registry(&claimer),
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::FlagClaimer {
+                flag: ReputationFlag::Abuse,
+            },
+        )
+    };
+
+    // Until the admin approves the faucet, it can neither reward nor flag.
+    expect_program_error(
+        client.send(&[flag_claimer(claimer)], &[]),
+        TapShieldErr::ReputationNotApproved,
+    );
+    client
+        .connection_mut()
+        .warp_to(START_TIME + 2 * COOLDOWN_SECONDS);
+    client.record_signed_claim(&claimer_keypair, 500).unwrap();
+    let registry = client.get_user_claim_registry(&claimer).unwrap().unwrap();
+    assert_eq!(registry.reputation(), INITIAL_REPUTATION);
+
+    let set_reputation_approval = instruction(
+        tap_shield::accounts::SetReputationApproval {
+            admin: owner,
+            protocol_config: pda::protocol_config(),
+            faucet_registry,
+        },
+        tap_shield::instruction::SetReputationApproval { approved: true },
+    );
+    client.send(&[set_reputation_approval], &[]).unwrap();
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + 4 * COOLDOWN_SECONDS);
+    client.record_signed_claim(&claimer_keypair, 500).unwrap();
+
+    let registry = client.get_user_claim_registry(&claimer).unwrap().unwrap();
+    assert_eq!(
+        registry.reputation(),
+        INITIAL_REPUTATION + tap_shield::states::WELL_SPACED_CLAIM_REWARD
+    );
+
+    client.send(&[flag_claimer(claimer)], &[]).unwrap();
+
+    let registry = client.get_user_claim_registry(&claimer).unwrap().unwrap();
+    assert_eq!(registry.reputation(), 410);
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + 6 * COOLDOWN_SECONDS);
+    expect_program_error(
+        client.record_claim(&claimer, 100),
+        TapShieldErr::ReputationTooLow,
+    );
+
+    // A faucet can only flag wallets that claimed there, and only a few times each.
+    assert!(client
+        .send(&[flag_claimer(Pubkey::new_unique())], &[])
+        .is_err());
+    for _ in 1..MAX_FLAGS_PER_FAUCET {
+        client.send(&[flag_claimer(claimer)], &[]).unwrap();
+    }
+    expect_program_error(
+        client.send(&[flag_claimer(claimer)], &[]),
+        TapShieldErr::FlagLimitReached,
+    );
+    let registry = client.get_user_claim_registry(&claimer).unwrap().unwrap();
+    assert_eq!(
+        registry.reputation(),
+        410 - (MAX_FLAGS_PER_FAUCET as u16 - 1) * ReputationFlag::Abuse.penalty()
+    );
+}
+
+#[test]
+fn check_eligibility_answers_without_writing() {
+    use tap_shield::{
+        instructions::Eligibility,
+        states::{BudgetPeriod, ClaimBudget, ClaimQuota, INITIAL_REPUTATION},
+    };
+
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let claimer = Pubkey::new_unique();
+
+    let update_faucet_policy = instruction(
+        tap_shield::accounts::UpdateFaucetPolicy {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::UpdateFaucetPolicy {
+            cooldown_seconds: COOLDOWN_SECONDS,
+            claim_quota: Some(ClaimQuota {
+                max_claims: 3,
+                max_amount: 1_000,
+                window_seconds: 86_400,
+            }),
+            require_claimer_signature: false,
+        },
+    );
+    let set_claim_budget = instruction(
+        tap_shield::accounts::SetClaimBudget {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::SetClaimBudget {
+            claim_budget: Some(ClaimBudget {
+                max_amount: 10_000,
+                period: BudgetPeriod::Seconds(86_400),
+            }),
+        },
+    );
+    client
+        .send(&[update_faucet_policy, set_claim_budget], &[])
+        .unwrap();
+
+    assert_eq!(
+        client.check_eligibility(&claimer, 100).unwrap(),
+        Eligibility {
+            eligible: true,
+            error_code: None,
+            seconds_remaining: 0,
+            remaining_claims: Some(3),
+            remaining_amount: Some(1_000),
+            remaining_budget: Some(10_000),
+            reputation: INITIAL_REPUTATION,
+        }
+    );
+    assert!(client.get_user_faucet_state(&claimer).unwrap().is_none());
+
+    client.record_claim(&claimer, 100).unwrap();
+    let registry_data = client
+        .connection()
+        .get_account_data(&faucet_registry)
+        .unwrap();
+    let user_faucet_state = pda::user_faucet_state(&claimer, &faucet_registry);
+    let state_data = client
+        .connection()
+        .get_account_data(&user_faucet_state)
+        .unwrap();
+
+    // An ineligible wallet gets an answer, not a failed transaction.
+    client.connection_mut().warp_to(START_TIME + 20);
+    assert_eq!(
+        client.check_eligibility(&claimer, 100).unwrap(),
+        Eligibility {
+            eligible: false,
+            error_code: Some(u32::from(TapShieldErr::ClaimTooRecent)),
+            seconds_remaining: COOLDOWN_SECONDS - 20,
+            remaining_claims: Some(2),
+            remaining_amount: Some(900),
+            remaining_budget: Some(9_900),
+            reputation: INITIAL_REPUTATION,
+        }
+    );
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    let eligibility = client.check_eligibility(&claimer, 100).unwrap();
+    assert!(eligibility.eligible);
+    assert_eq!(eligibility.seconds_remaining, 0);
+
+    assert_eq!(
+        client
+            .connection()
+            .get_account_data(&faucet_registry)
+            .unwrap(),
+        registry_data
+    );
+    assert_eq!(
+        client
+            .connection()
+            .get_account_data(&user_faucet_state)
+            .unwrap(),
+        state_data
+    );
+}
+
+#[test]
+fn claims_batch_is_atomic_or_skips_ineligible_entries() {
+    use tap_shield::instructions::BatchMode;
+
+    let mut client = setup_faucet();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    client.record_claim(&alice, 100).unwrap();
+
+    expect_program_error(
+        client.record_claims_batch(&[(bob, 100), (alice, 100)], BatchMode::Atomic),
+        TapShieldErr::ClaimTooRecent,
+    );
+    assert!(client.get_user_claim_registry(&bob).unwrap().is_none());
+
+    // Bob's second entry sees the state his first one wrote and hits the cooldown.
+    client
+        .record_claims_batch(
+            &[(bob, 100), (alice, 100), (bob, 100)],
+            BatchMode::SkipIneligible,
+        )
+        .unwrap();
+
+    let stats = client.get_faucet_stats().unwrap();
+    assert_eq!(stats.total_claims, 2);
+    assert_eq!(stats.unique_claimers, 2);
+
+    let bob_state = client.get_user_faucet_state(&bob).unwrap().unwrap();
+    assert_eq!(bob_state.claim_count, 1);
+    // Batched claims are not signed by the claimers, so the global registry stays untouched.
+    assert!(client.get_user_claim_registry(&bob).unwrap().is_none());
+    assert_eq!(
+        client
+            .get_user_faucet_state(&alice)
+            .unwrap()
+            .unwrap()
+            .claim_count,
+        1
+    );
+
+    // Single claims keep working after a batch left claim-record indexes unused.
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    client.record_claim(&alice, 100).unwrap();
+}
+
+#[test]
+fn claim_log_replaces_claim_records_until_closed() {
+    use tap_shield::instructions::BatchMode;
+
+    let mut client = setup_faucet();
+    let (alice, bob, carol) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let claim_log = client.create_claim_log().unwrap();
+    assert_eq!(client.record_claim(&alice, 100).unwrap(), claim_log);
+    client
+        .record_claims_batch(&[(bob, 200), (carol, 300)], BatchMode::Atomic)
+        .unwrap();
+
+    let faucet_registry = client.faucet_registry();
+    assert!(client
+        .connection()
+        .get_account_data(&pda::claim_record(&alice, &faucet_registry, 0))
+        .unwrap()
+        .is_none());
+
+    let log = client.get_claim_log().unwrap().unwrap();
+    assert_eq!(log.faucet, faucet_registry);
+    assert_eq!(log.total_entries, 3);
+    let entries: Vec<_> = log
+        .recent()
+        .map(|entry| (entry.claimer, entry.amount, entry.timestamp))
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            (alice, 100, START_TIME),
+            (bob, 200, START_TIME),
+            (carol, 300, START_TIME)
+        ]
+    );
+
+    // A claim record instead of the log is refused while the faucet has one.
+    let dave = Pubkey::new_unique();
+    let with_record = instructions::record_claim(
+        &client.payer().pubkey(),
+        &client.faucet_id(),
+        &dave,
+        3,
+        100,
+        None,
+        None,
+    );
+    expect_program_error(
+        client.send(&[with_record], &[]),
+        TapShieldErr::ClaimLogMismatch,
+    );
+
+    client.close_claim_log().unwrap();
+    assert!(client.get_claim_log().unwrap().is_none());
+    assert!(client
+        .connection()
+        .get_account_data(&claim_log)
+        .unwrap()
+        .is_none());
+
+    let claim_record = client.record_claim(&dave, 100).unwrap();
+    let record: tap_shield::states::ClaimRecord = client.fetch(&claim_record).unwrap().unwrap();
+    assert_eq!(record.claimer, dave);
+}
+
+#[test]
+fn paused_faucet_rejects_claims_and_closes_only_without_claim_records() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let claimer = Pubkey::new_unique();
+
+    let pause_faucet = instruction(
+        tap_shield::accounts::PauseFaucet {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::PauseFaucet {},
+    );
+    client.send(&[pause_faucet], &[]).unwrap();
+    expect_program_error(
+        client.record_claim(&claimer, 100),
+        TapShieldErr::FaucetPaused,
+    );
+
+    let resume_faucet = instruction(
+        tap_shield::accounts::ResumeFaucet {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::ResumeFaucet {},
+    );
+    client.send(&[resume_faucet], &[]).unwrap();
+    let claim_record = client.record_claim(&claimer, 100).unwrap();
+
+    let close_faucet = instruction(
+        tap_shield::accounts::CloseFaucet {
+            owner,
+            faucet_registry,
+            vault: pda::vault(&faucet_registry),
+            vault_ata: None,
+            token_program: None,
+        },
+        tap_shield::instruction::CloseFaucet {},
+    );
+    expect_program_error(
+        client.send(std::slice::from_ref(&close_faucet), &[]),
+        TapShieldErr::FaucetHasOpenClaimRecords,
+    );
+
+    let mut close_claim_records = instruction(
+        tap_shield::accounts::CloseClaimRecords {
+            owner,
+            claimer,
+            faucet_registry,
+            protocol_config: pda::protocol_config(),
+            user_claim_registry: pda::user_claim_registry(&claimer),
+        },
+        tap_shield::instruction::CloseClaimRecords {},
+    );
+    close_claim_records
+        .accounts
+        .push(AccountMeta::new(claim_record, false));
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    client.send(&[close_claim_records], &[]).unwrap();
+    client.send(&[close_faucet], &[]).unwrap();
+    assert!(client
+        .fetch::<tap_shield::states::FaucetRegistry>(&faucet_registry)
+        .unwrap()
+        .is_none());
+
+    // Re-registering starts the claim index at 0 again, which is free now.
+    client
+        .register_faucet("Devnet Faucet", COOLDOWN_SECONDS)
+        .unwrap();
+    assert_eq!(client.record_claim(&claimer, 100).unwrap(), claim_record);
+}
+
+#[test]
+fn metadata_update_grows_registries_from_older_layouts() {
+    use tap_shield::states::{FaucetRegistry, MAX_URI_LEN};
+
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+
+    let update_faucet_metadata = |uri: String| {
+        instruction(
+            tap_shield::accounts::UpdateFaucetMetadata {
+                owner,
+                faucet_registry,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::UpdateFaucetMetadata {
+                name: None,
+                uri: Some(uri),
+                category: Some("devnet".to_string()),
+                contact: None,
+            },
+        )
+    };
+
+    expect_program_error(
+        client.send(&[update_faucet_metadata("u".repeat(MAX_URI_LEN + 1))], &[]),
+        TapShieldErr::MetadataTooLong,
+    );
+
+    // Cut the registry off after `bump`, as it was stored before `required_bond` and the
+    // fields after it were appended.
+    let registry = client.get_faucet_stats().unwrap();
+    let mut data = Vec::new();
+    registry.try_serialize(&mut data).unwrap();
+    let appended_fields = 8 + 1 + 1 + 1 + 2 + 8 + 1 + registry.reserved.len();
+    data.truncate(data.len() - appended_fields);
+    client
+        .connection_mut()
+        .set_program_account(&faucet_registry, &data);
+    assert!(client.get_faucet_stats().is_err());
+
+    // Only the owner can make the registry grow, and before anything is resized.
+    let intruder = Keypair::new();
+    client
+        .connection_mut()
+        .fund(&intruder.pubkey(), 1_000_000_000);
+    let mut forged = update_faucet_metadata("https://faucet.example".to_string());
+    forged.accounts[0].pubkey = intruder.pubkey();
+    expect_program_error(
+        client.send(&[forged], &[&intruder]),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+    assert_eq!(
+        client
+            .connection()
+            .get_account_data(&faucet_registry)
+            .unwrap()
+            .unwrap(),
+        data
+    );
+
+    client
+        .send(
+            &[update_faucet_metadata("https://faucet.example".to_string())],
+            &[],
+        )
+        .unwrap();
+
+    let registry = client.get_faucet_stats().unwrap();
+    assert_eq!(registry.name, "Devnet Faucet");
+    assert_eq!(registry.uri, "https://faucet.example");
+    assert_eq!(registry.category, "devnet");
+    assert_eq!(registry.required_bond, 0);
+    assert!(registry.claim_log.is_none());
+    assert_eq!(
+        client
+            .connection()
+            .get_account_data(&faucet_registry)
+            .unwrap()
+            .unwrap()
+            .len(),
+        8 + FaucetRegistry::INIT_SPACE
+    );
+}
+
+#[test]
+fn attested_claims_require_a_valid_ed25519_instruction() {
+    use anchor_lang::solana_program::sysvar;
+    use solana_ed25519_program::new_ed25519_instruction_with_signature;
+    use tap_shield::attestation::attestation_message;
+
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let attester = Keypair::new();
+    let claimer = Pubkey::new_unique();
+
+    let set_attesters = instruction(
+        tap_shield::accounts::SetAttesters {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::SetAttesters {
+            attesters: vec![attester.pubkey()],
+        },
+    );
+    client.send(&[set_attesters], &[]).unwrap();
+
+    let attestation = |signer: &Keypair, amount: u64, expiry: i64| {
+        let message = attestation_message(&claimer, &faucet_registry, amount, expiry);
+        let signature = signer.sign_message(&message);
+        new_ed25519_instruction_with_signature(
+            &message,
+            signature.as_array(),
+            &signer.pubkey().to_bytes(),
+        )
+    };
+    let attested_claim = |client: &mut TapShieldClient<TestValidator>,
+                          attestation: Instruction,
+                          with_sysvar: bool| {
+        let record_claim = instruction(
+            tap_shield::accounts::RecordClaim {
+                operator: owner,
+                claimer,
+                faucet_registry,
+                protocol_config: pda::protocol_config(),
+                claim_record: Some(pda::claim_record(
+                    &claimer,
+                    &faucet_registry,
+                    client.get_faucet_stats().unwrap().total_claims,
+                )),
+                claim_log: None,
+                user_claim_registry: pda::user_claim_registry(&claimer),
+                user_faucet_state: pda::user_faucet_state(&claimer, &faucet_registry),
+                protocol_block_entry: pda::protocol_block_entry(&claimer),
+                faucet_list_entry: pda::faucet_list_entry(&faucet_registry, &claimer),
+                claimer_bond: pda::claimer_bond(&claimer),
+                cluster_membership: pda::cluster_membership(&faucet_registry, &claimer),
+                wallet_cluster: None,
+                instruction_sysvar: with_sysvar.then_some(sysvar::instructions::ID),
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::RecordClaim {
+                claimer_pubkey: claimer,
+                amount: 100,
+            },
+        );
+        client.send(&[attestation, record_claim], &[])
+    };
+
+    let expiry = START_TIME + 300;
+
+    expect_program_error(
+        client.record_claim(&claimer, 100),
+        TapShieldErr::AttestationMissing,
+    );
+    expect_program_error(
+        attested_claim(&mut client, attestation(&attester, 100, expiry), false),
+        TapShieldErr::AttestationMissing,
+    );
+    expect_program_error(
+        attested_claim(&mut client, attestation(&Keypair::new(), 100, expiry), true),
+        TapShieldErr::UnknownAttester,
+    );
+    expect_program_error(
+        attested_claim(&mut client, attestation(&attester, 200, expiry), true),
+        TapShieldErr::InvalidAttestation,
+    );
+    expect_program_error(
+        attested_claim(
+            &mut client,
+            attestation(&attester, 100, START_TIME - 1),
+            true,
+        ),
+        TapShieldErr::AttestationExpired,
+    );
+
+    // Offsets pointing into another instruction (here the ed25519 one itself, at index 0)
+    // still verify, but could smuggle in a message the program never looks at.
+    let mut foreign_offsets = attestation(&attester, 100, expiry);
+    for index_offset in [4, 8, 14] {
+        foreign_offsets.data[index_offset..index_offset + 2].copy_from_slice(&0u16.to_le_bytes());
+    }
+    expect_program_error(
+        attested_claim(&mut client, foreign_offsets, true),
+        TapShieldErr::InvalidAttestation,
+    );
+
+    attested_claim(&mut client, attestation(&attester, 100, expiry), true).unwrap();
+    assert_eq!(client.get_faucet_stats().unwrap().total_claims, 1);
+}
+
+#[test]
+fn faucet_ownership_moves_only_when_the_pending_owner_accepts() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let new_owner = Keypair::new();
+    let intruder = Keypair::new();
+
+    let transfer_faucet_ownership = |owner: Pubkey, new_owner: Option<Pubkey>| {
+        instruction(
+            tap_shield::accounts::TransferFaucetOwnership {
+                owner,
+                faucet_registry,
+            },
+            tap_shield::instruction::TransferFaucetOwnership { new_owner },
+        )
+    };
+    let accept_faucet_ownership = |new_owner: Pubkey| {
+        instruction(
+            tap_shield::accounts::AcceptFaucetOwnership {
+                new_owner,
+                faucet_registry,
+            },
+            tap_shield::instruction::AcceptFaucetOwnership {},
+        )
+    };
+
+    expect_program_error(
+        client.send(
+            &[transfer_faucet_ownership(
+                intruder.pubkey(),
+                Some(intruder.pubkey()),
+            )],
+            &[&intruder],
+        ),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+
+    client
+        .send(
+            &[transfer_faucet_ownership(owner, Some(new_owner.pubkey()))],
+            &[],
+        )
+        .unwrap();
+    let faucet = client.get_faucet_stats().unwrap();
+    assert_eq!(faucet.owner, owner);
+    assert_eq!(faucet.pending_owner, Some(new_owner.pubkey()));
+
+    expect_program_error(
+        client.send(&[accept_faucet_ownership(intruder.pubkey())], &[&intruder]),
+        TapShieldErr::NotPendingOwner,
+    );
+
+    client
+        .send(
+            &[accept_faucet_ownership(new_owner.pubkey())],
+            &[&new_owner],
+        )
+        .unwrap();
+    let faucet = client.get_faucet_stats().unwrap();
+    assert_eq!(faucet.owner, new_owner.pubkey());
+    assert_eq!(faucet.pending_owner, None);
+
+    // The previous owner lost every owner right, including proposing a transfer.
+    expect_program_error(
+        client.send(&[transfer_faucet_ownership(owner, Some(owner))], &[]),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+
+    // `None` cancels a pending transfer before it is accepted.
+    client
+        .send(
+            &[transfer_faucet_ownership(
+                new_owner.pubkey(),
+                Some(intruder.pubkey()),
+            )],
+            &[&new_owner],
+        )
+        .unwrap();
+    client
+        .send(
+            &[transfer_faucet_ownership(new_owner.pubkey(), None)],
+            &[&new_owner],
+        )
+        .unwrap();
+    assert_eq!(client.get_faucet_stats().unwrap().pending_owner, None);
+    expect_program_error(
+        client.send(&[accept_faucet_ownership(intruder.pubkey())], &[&intruder]),
+        TapShieldErr::NotPendingOwner,
+    );
+    assert_eq!(client.get_faucet_stats().unwrap().owner, new_owner.pubkey());
+}
+
+#[test]
+fn recorders_can_record_claims_until_rotated_or_removed() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let recorder = Keypair::new();
+    let rotated = Keypair::new();
+    for operator in [&recorder, &rotated] {
+        client
+            .connection_mut()
+            .fund(&operator.pubkey(), 1_000_000_000);
+    }
+
+    let record_as = |client: &mut TapShieldClient<TestValidator>, operator: &Keypair| {
+        let claim_index = client.get_faucet_stats().unwrap().total_claims;
+        let instruction = instructions::record_claim(
+            &operator.pubkey(),
+            &client.faucet_id(),
+            &Pubkey::new_unique(),
+            claim_index,
+            100,
+            None,
+            None,
+        );
+        client.send(&[instruction], &[operator])
+    };
+
+    let add_recorder = instruction(
+        tap_shield::accounts::AddRecorder {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::AddRecorder {
+            recorder: recorder.pubkey(),
+        },
+    );
+    client
+        .send(std::slice::from_ref(&add_recorder), &[])
+        .unwrap();
+    expect_program_error(
+        client.send(&[add_recorder], &[]),
+        TapShieldErr::RecorderAlreadyExists,
+    );
+    record_as(&mut client, &recorder).unwrap();
+
+    // Recorders only record; managing the faucet stays with the owner.
+    let add_by_recorder = instruction(
+        tap_shield::accounts::AddRecorder {
+            owner: recorder.pubkey(),
+            faucet_registry,
+        },
+        tap_shield::instruction::AddRecorder {
+            recorder: rotated.pubkey(),
+        },
+    );
+    expect_program_error(
+        client.send(&[add_by_recorder], &[&recorder]),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+
+    let rotate_recorder = instruction(
+        tap_shield::accounts::RotateRecorder {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::RotateRecorder {
+            old_recorder: recorder.pubkey(),
+            new_recorder: rotated.pubkey(),
+        },
+    );
+    client.send(&[rotate_recorder], &[]).unwrap();
+    expect_program_error(
+        record_as(&mut client, &recorder),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+    record_as(&mut client, &rotated).unwrap();
+
+    let remove_recorder = instruction(
+        tap_shield::accounts::RemoveRecorder {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::RemoveRecorder {
+            recorder: rotated.pubkey(),
+        },
+    );
+    client
+        .send(std::slice::from_ref(&remove_recorder), &[])
+        .unwrap();
+    expect_program_error(
+        client.send(&[remove_recorder], &[]),
+        TapShieldErr::RecorderNotFound,
+    );
+    expect_program_error(
+        record_as(&mut client, &rotated),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+
+    assert!(client.get_faucet_stats().unwrap().recorders.is_empty());
+    assert_eq!(client.get_faucet_stats().unwrap().total_claims, 2);
+}
+
+#[test]
+fn block_and_allow_lists_gate_claims() {
+    use tap_shield::states::ListKind;
+
+    let mut client = setup_faucet();
+    let payer = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let (blocked, expiring, allowed) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let add_protocol_block = instruction(
+        tap_shield::accounts::AddProtocolBlock {
+            admin: payer,
+            protocol_config: pda::protocol_config(),
+            block_entry: pda::protocol_block_entry(&blocked),
+            system_program: system_program::ID,
+        },
+        tap_shield::instruction::AddProtocolBlock {
+            wallet: blocked,
+            reason: "sybil farm".to_string(),
+            expires_at: None,
+        },
+    );
+    client.send(&[add_protocol_block], &[]).unwrap();
+    expect_program_error(
+        client.record_claim(&blocked, 100),
+        TapShieldErr::WalletBlocked,
+    );
+
+    let remove_protocol_block = instruction(
+        tap_shield::accounts::RemoveProtocolBlock {
+            admin: payer,
+            protocol_config: pda::protocol_config(),
+            block_entry: pda::protocol_block_entry(&blocked),
+        },
+        tap_shield::instruction::RemoveProtocolBlock {},
+    );
+    client.send(&[remove_protocol_block], &[]).unwrap();
+    client.record_claim(&blocked, 100).unwrap();
+
+    // Faucet entries stop applying once they expire.
+    let set_faucet_list_entry = |wallet: Pubkey, kind: ListKind, expires_at: Option<i64>| {
+        instruction(
+            tap_shield::accounts::SetFaucetListEntry {
+                owner: payer,
+                faucet_registry,
+                list_entry: pda::faucet_list_entry(&faucet_registry, &wallet),
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::SetFaucetListEntry {
+                wallet,
+                kind,
+                reason: String::new(),
+                expires_at,
+            },
+        )
+    };
+    client
+        .send(
+            &[set_faucet_list_entry(
+                expiring,
+                ListKind::Blocked,
+                Some(START_TIME + 100),
+            )],
+            &[],
+        )
+        .unwrap();
+    expect_program_error(
+        client.record_claim(&expiring, 100),
+        TapShieldErr::WalletBlocked,
+    );
+    client.connection_mut().warp_to(START_TIME + 100);
+    client.record_claim(&expiring, 100).unwrap();
+
+    let set_allowlist_only = instruction(
+        tap_shield::accounts::SetAllowlistOnly {
+            owner: payer,
+            faucet_registry,
+        },
+        tap_shield::instruction::SetAllowlistOnly {
+            allowlist_only: true,
+        },
+    );
+    client.send(&[set_allowlist_only], &[]).unwrap();
+    expect_program_error(
+        client.record_claim(&allowed, 100),
+        TapShieldErr::WalletNotAllowlisted,
+    );
+
+    client
+        .send(
+            &[set_faucet_list_entry(allowed, ListKind::Allowed, None)],
+            &[],
+        )
+        .unwrap();
+    client.record_claim(&allowed, 100).unwrap();
+
+    let remove_faucet_list_entry = instruction(
+        tap_shield::accounts::RemoveFaucetListEntry {
+            owner: payer,
+            faucet_registry,
+            list_entry: pda::faucet_list_entry(&faucet_registry, &allowed),
+        },
+        tap_shield::instruction::RemoveFaucetListEntry {},
+    );
+    client.send(&[remove_faucet_list_entry], &[]).unwrap();
+    client
+        .connection_mut()
+        .warp_to(START_TIME + 100 + COOLDOWN_SECONDS);
+    expect_program_error(
+        client.record_claim(&allowed, 100),
+        TapShieldErr::WalletNotAllowlisted,
+    );
+}
+
+#[test]
+fn claim_records_close_once_out_of_the_history_window() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let claimer_keypair = Keypair::new();
+    let (claimer, other) = (claimer_keypair.pubkey(), Pubkey::new_unique());
+
+    // Signed, so the claimer has a user registry to fold the closed records into.
+    let first = client.record_signed_claim(&claimer_keypair, 100).unwrap();
+    client
+        .connection_mut()
+        .warp_to(START_TIME + COOLDOWN_SECONDS);
+    let second = client.record_signed_claim(&claimer_keypair, 200).unwrap();
+    let other_record = client.record_claim(&other, 300).unwrap();
+
+    let close_claim_records = |owner: Pubkey, claim_records: &[Pubkey]| {
+        let mut instruction = instruction(
+            tap_shield::accounts::CloseClaimRecords {
+                owner,
+                claimer,
+                faucet_registry,
+                protocol_config: pda::protocol_config(),
+                user_claim_registry: pda::user_claim_registry(&claimer),
+            },
+            tap_shield::instruction::CloseClaimRecords {},
+        );
+        instruction.accounts.extend(
+            claim_records
+                .iter()
+                .map(|claim_record| AccountMeta::new(*claim_record, false)),
+        );
+        instruction
+    };
+
+    expect_program_error(
+        client.send(&[close_claim_records(owner, &[])], &[]),
+        TapShieldErr::InvalidInput,
+    );
+    expect_program_error(
+        client.send(&[close_claim_records(owner, &[first, second])], &[]),
+        TapShieldErr::ClaimRecordStillActive,
+    );
+    expect_program_error(
+        client.send(&[close_claim_records(owner, &[other_record])], &[]),
+        TapShieldErr::InvalidClaimRecord,
+    );
+
+    client
+        .connection_mut()
+        .warp_to(START_TIME + 2 * COOLDOWN_SECONDS);
+    let stranger = Keypair::new();
+    expect_program_error(
+        client.send(
+            &[close_claim_records(stranger.pubkey(), &[first, second])],
+            &[&stranger],
+        ),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+
+    let rent = client.connection().lamports(&first) + client.connection().lamports(&second);
+    let owner_lamports = client.connection().lamports(&owner);
+    client
+        .send(&[close_claim_records(owner, &[first, second])], &[])
+        .unwrap();
+
+    assert!(client.get_user_history(&claimer).unwrap().is_empty());
+    assert_eq!(client.get_user_history(&other).unwrap().len(), 1);
+    // The owner signs and pays the 5000 lamport fee.
+    assert_eq!(
+        client.connection().lamports(&owner),
+        owner_lamports + rent - 5_000
+    );
+
+    let stats = client.get_faucet_stats().unwrap();
+    assert_eq!(stats.total_claims, 3);
+    assert_eq!(stats.closed_claims, 2);
+    assert_eq!(stats.closed_amount, 300);
+    assert_eq!(stats.open_claim_records, 1);
+
+    let user_registry = client.get_user_claim_registry(&claimer).unwrap().unwrap();
+    assert_eq!(user_registry.total_claims_across_faucets, 2);
+    assert_eq!(user_registry.closed_claims, 2);
+    assert_eq!(user_registry.closed_amount, 300);
+}
+
+#[test]
+fn claim_budget_is_exhausted_until_the_next_period() {
+    use tap_shield::states::{BudgetPeriod, ClaimBudget};
+
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+
+    let set_claim_budget = |max_amount: u64| {
+        instruction(
+            tap_shield::accounts::SetClaimBudget {
+                owner,
+                faucet_registry,
+            },
+            tap_shield::instruction::SetClaimBudget {
+                claim_budget: Some(ClaimBudget {
+                    max_amount,
+                    period: BudgetPeriod::Seconds(3_600),
+                }),
+            },
+        )
+    };
+    expect_program_error(
+        client.send(&[set_claim_budget(0)], &[]),
+        TapShieldErr::InvalidBudget,
+    );
+    client.send(&[set_claim_budget(250)], &[]).unwrap();
+
+    for _ in 0..2 {
+        client.record_claim(&Pubkey::new_unique(), 100).unwrap();
+    }
+    expect_program_error(
+        client.record_claim(&Pubkey::new_unique(), 100),
+        TapShieldErr::BudgetExhausted,
+    );
+    client.record_claim(&Pubkey::new_unique(), 50).unwrap();
+    expect_program_error(
+        client.record_claim(&Pubkey::new_unique(), 1),
+        TapShieldErr::BudgetExhausted,
+    );
+    assert_eq!(client.get_faucet_stats().unwrap().budget_spent, 250);
+
+    // Periods are aligned to the unix epoch, so the next one starts at the next full hour.
+    let next_period = (START_TIME / 3_600 + 1) * 3_600;
+    client.connection_mut().warp_to(next_period - 1);
+    expect_program_error(
+        client.record_claim(&Pubkey::new_unique(), 1),
+        TapShieldErr::BudgetExhausted,
+    );
+    client.connection_mut().warp_to(next_period);
+    client.record_claim(&Pubkey::new_unique(), 100).unwrap();
+
+    let stats = client.get_faucet_stats().unwrap();
+    assert_eq!(stats.budget_spent, 100);
+    assert_eq!(stats.total_amount, 350);
+}
+
+#[test]
+fn check_and_record_only_accepts_the_bound_program() {
+    let mut client = setup_faucet();
+    let owner = client.payer().pubkey();
+    let faucet_registry = client.faucet_registry();
+    let claimer = Pubkey::new_unique();
+
+    let check_and_record = |client: &TapShieldClient<TestValidator>, caller_authority: Pubkey| {
+        let claim_index = client.get_faucet_stats().unwrap().total_claims;
+        instruction(
+            tap_shield::accounts::CheckAndRecord {
+                caller_authority,
+                payer: owner,
+                claimer,
+                faucet_registry,
+                protocol_config: pda::protocol_config(),
+                claim_record: Some(pda::claim_record(&claimer, &faucet_registry, claim_index)),
+                user_claim_registry: pda::user_claim_registry(&claimer),
+                user_faucet_state: pda::user_faucet_state(&claimer, &faucet_registry),
+                protocol_block_entry: pda::protocol_block_entry(&claimer),
+                faucet_list_entry: pda::faucet_list_entry(&faucet_registry, &claimer),
+                claimer_bond: pda::claimer_bond(&claimer),
+                cluster_membership: pda::cluster_membership(&faucet_registry, &claimer),
+                wallet_cluster: None,
+                instruction_sysvar: None,
+                system_program: system_program::ID,
+                claim_log: None,
+            },
+            tap_shield::instruction::CheckAndRecord { amount: 100 },
+        )
+    };
+    let via_caller = |client: &TapShieldClient<TestValidator>, caller: Pubkey| {
+        let (authority, _) = Pubkey::find_program_address(&[CPI_AUTHORITY_SEED], &caller);
+        let mut instruction = check_and_record(client, authority);
+        instruction.program_id = caller;
+        // The authority can't sign the transaction, the caller signs for it in the CPI.
+        for meta in &mut instruction.accounts {
+            meta.is_signer &= meta.pubkey != authority;
+        }
+        instruction
+            .accounts
+            .insert(0, AccountMeta::new_readonly(tap_shield::ID, false));
+        instruction
+    };
+
+    let unbound = via_caller(&client, CALLER_PROGRAM);
+    expect_program_error(client.send(&[unbound], &[]), TapShieldErr::UnboundCaller);
+
+    let bind_faucet_program = instruction(
+        tap_shield::accounts::BindFaucetProgram {
+            owner,
+            faucet_registry,
+        },
+        tap_shield::instruction::BindFaucetProgram {
+            program_id: Some(CALLER_PROGRAM),
+        },
+    );
+    client.send(&[bind_faucet_program], &[]).unwrap();
+
+    let other_caller = via_caller(&client, OTHER_CALLER_PROGRAM);
+    expect_program_error(
+        client.send(&[other_caller], &[]),
+        TapShieldErr::UnboundCaller,
+    );
+    // A plain keypair can't stand in for the bound program's PDA.
+    let impostor = Keypair::new();
+    let direct = check_and_record(&client, impostor.pubkey());
+    expect_program_error(
+        client.send(&[direct], &[&impostor]),
+        TapShieldErr::UnboundCaller,
+    );
+
+    let bound = via_caller(&client, CALLER_PROGRAM);
+    client.send(&[bound], &[]).unwrap();
+
+    let history = client.get_user_history(&claimer).unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].1.amount, 100);
+    assert_eq!(client.get_faucet_stats().unwrap().total_claims, 1);
+}
+
+/// Discriminator, then `fields` zero-padded to the legacy layout's `INIT_SPACE`, as the
+/// pre-versioning program allocated it.
+fn legacy_account<T: Discriminator>(fields: &[&[u8]], legacy_space: usize) -> Vec<u8> {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    for field in fields {
+        data.extend_from_slice(field);
+    }
+    data.resize(T::DISCRIMINATOR.len() + legacy_space, 0);
+    data
+}
+
+#[test]
+fn legacy_faucet_registry_migrates_in_place() {
+    use tap_shield::states::{FaucetRegistry, LegacyFaucetRegistry};
+
+    let mut client = setup();
+    let operator = client.payer().pubkey();
+    let faucet_registry = pda::faucet_registry(&operator);
+
+    let name = "Legacy Faucet";
+    let legacy = legacy_account::<FaucetRegistry>(
+        &[
+            operator.as_ref(),
+            &(name.len() as u32).to_le_bytes(),
+            name.as_bytes(),
+            &3u64.to_le_bytes(),
+            &START_TIME.to_le_bytes(),
+        ],
+        LegacyFaucetRegistry::INIT_SPACE,
+    );
+    client
+        .connection_mut()
+        .set_program_account(&faucet_registry, &legacy);
+
+    let migrate_faucet_registry = |owner: Pubkey| {
+        instruction(
+            tap_shield::accounts::MigrateFaucetRegistry {
+                owner,
+                faucet_registry,
+                system_program: system_program::ID,
+            },
+            tap_shield::instruction::MigrateFaucetRegistry {
+                cooldown_seconds: COOLDOWN_SECONDS,
+            },
+        )
+    };
+
+    let stranger = Keypair::new();
+    client
+        .connection_mut()
+        .fund(&stranger.pubkey(), 1_000_000_000);
+    expect_program_error(
+        client.send(&[migrate_faucet_registry(stranger.pubkey())], &[&stranger]),
+        TapShieldErr::UnauthorizedFaucet,
+    );
+
+    client
+        .send(&[migrate_faucet_registry(operator)], &[])
+        .unwrap();
+    expect_program_error(
+        client.send(&[migrate_faucet_registry(operator)], &[]),
+        TapShieldErr::AccountAlreadyMigrated,
+    );
+
+    // Legacy registries live at `["faucet", operator]`, so the operator is the faucet id.
+    let mut client = client.with_faucet_id(operator);
+    let registry = client.get_faucet_stats().unwrap();
+    assert_eq!(registry.version, FaucetRegistry::VERSION);
+    assert_eq!(registry.faucet_id, operator);
+    assert_eq!(registry.owner, operator);
+    assert_eq!(registry.name, name);
+    assert_eq!(registry.total_claims, 3);
+    assert_eq!(registry.open_claim_records, 3);
+    assert_eq!(registry.created_at, START_TIME);
+    assert_eq!(registry.cooldown_seconds, COOLDOWN_SECONDS);
+    assert_eq!(
+        client
+            .connection()
+            .get_account_data(&faucet_registry)
+            .unwrap()
+            .unwrap()
+            .len(),
+        8 + FaucetRegistry::INIT_SPACE
+    );
+
+    // Claim indexes carry on from the legacy count.
+    let claimer = Pubkey::new_unique();
+    assert_eq!(
+        client.record_claim(&claimer, 100).unwrap(),
+        pda::claim_record(&claimer, &faucet_registry, 3)
+    );
+}
+
+#[test]
+fn legacy_user_claim_registry_migrates_in_place() {
+    use tap_shield::states::{LegacyUserClaimRegistry, UserClaimRegistry, INITIAL_REPUTATION};
+
+    let mut client = setup();
+    let user = Pubkey::new_unique();
+    let last_faucet = Pubkey::new_unique();
+    let (user_claim_registry, bump) =
+        Pubkey::find_program_address(&[b"user_registry", user.as_ref()], &tap_shield::ID);
+
+    let legacy = legacy_account::<UserClaimRegistry>(
+        &[
+            user.as_ref(),
+            &START_TIME.to_le_bytes(),
+            last_faucet.as_ref(),
+            &5u64.to_le_bytes(),
+            &[bump],
+        ],
+        LegacyUserClaimRegistry::INIT_SPACE,
+    );
+    client
+        .connection_mut()
+        .set_program_account(&user_claim_registry, &legacy);
+
+    // Anyone can migrate, the payer only covers the extra rent.
+    let migrate_user_claim_registry = instruction(
+        tap_shield::accounts::MigrateUserClaimRegistry {
+            payer: client.payer().pubkey(),
+            user_claim_registry,
+            system_program: system_program::ID,
+        },
+        tap_shield::instruction::MigrateUserClaimRegistry {},
+    );
+    client
+        .send(std::slice::from_ref(&migrate_user_claim_registry), &[])
+        .unwrap();
+    expect_program_error(
+        client.send(&[migrate_user_claim_registry], &[]),
+        TapShieldErr::AccountAlreadyMigrated,
+    );
+
+    let registry = client.get_user_claim_registry(&user).unwrap().unwrap();
+    assert_eq!(registry.version, UserClaimRegistry::VERSION);
+    assert_eq!(registry.user, user);
+    assert_eq!(registry.last_claim_timestamp, START_TIME);
+    assert_eq!(registry.last_faucet, last_faucet);
+    assert_eq!(registry.total_claims_across_faucets, 5);
+    assert_eq!(registry.bump, bump);
+    assert_eq!(registry.reputation(), INITIAL_REPUTATION);
+}
+
+#[test]
+fn legacy_claim_record_migrates_in_place() {
+    use tap_shield::states::{ClaimRecord, LegacyClaimRecord};
+
+    let mut client = setup();
+    let claimer = Pubkey::new_unique();
+    let faucet_id = Pubkey::new_unique();
+    let claim_record = Pubkey::new_unique();
+
+    let legacy_fields: [&[u8]; 4] = [
+        claimer.as_ref(),
+        faucet_id.as_ref(),
+        &1_000u64.to_le_bytes(),
+        &START_TIME.to_le_bytes(),
+    ];
+    let migrate_claim_record = instruction(
+        tap_shield::accounts::MigrateClaimRecord {
+            payer: client.payer().pubkey(),
+            claim_record,
+            system_program: system_program::ID,
+        },
+        tap_shield::instruction::MigrateClaimRecord {},
+    );
+
+    // Neither legacy-sized nor versioned: some layout this program never wrote.
+    let unknown = legacy_account::<ClaimRecord>(&legacy_fields, LegacyClaimRecord::INIT_SPACE + 1);
+    client
+        .connection_mut()
+        .set_program_account(&claim_record, &unknown);
+    expect_program_error(
+        client.send(std::slice::from_ref(&migrate_claim_record), &[]),
+        TapShieldErr::UnsupportedAccountVersion,
+    );
+
+    let legacy = legacy_account::<ClaimRecord>(&legacy_fields, LegacyClaimRecord::INIT_SPACE);
+    client
+        .connection_mut()
+        .set_program_account(&claim_record, &legacy);
+    client
+        .send(std::slice::from_ref(&migrate_claim_record), &[])
+        .unwrap();
+    expect_program_error(
+        client.send(&[migrate_claim_record], &[]),
+        TapShieldErr::AccountAlreadyMigrated,
+    );
+
+    let record = client.fetch::<ClaimRecord>(&claim_record).unwrap().unwrap();
+    assert_eq!(record.version, ClaimRecord::VERSION);
+    assert_eq!(record.claimer, claimer);
+    assert_eq!(record.faucet_id, faucet_id);
+    assert_eq!(record.amount, 1_000);
+    assert_eq!(record.timestamp, START_TIME);
+}
@@ -0,0 +1,66 @@
+//! Stand-in for `solana-cpi`, whose functions do nothing outside `target_os = "solana"`.
+//! Anchor 0.32 sets every instruction's return data through `solana_cpi::set_return_data`,
+//! so natively it would be lost. Here the calls go through the `Stubs` a test harness
+//! registers, e.g. the `solana-program-test` syscall stubs. Without stubs they behave like
+//! upstream off-chain. Only the `native-tests` workspace patches it in.
+
+use std::sync::OnceLock;
+
+use solana_account_info::AccountInfo;
+use solana_instruction::Instruction;
+use solana_program_error::ProgramResult;
+use solana_pubkey::Pubkey;
+
+/// Maximum size that can be set using [`set_return_data`].
+pub const MAX_RETURN_DATA: usize = 1024;
+
+/// Off-chain implementations of the syscalls behind this crate.
+pub struct Stubs {
+    pub invoke_signed: fn(&Instruction, &[AccountInfo], &[&[&[u8]]]) -> ProgramResult,
+    pub set_return_data: fn(&[u8]),
+    pub get_return_data: fn() -> Option<(Pubkey, Vec<u8>)>,
+}
+
+static STUBS: OnceLock<Stubs> = OnceLock::new();
+
+/// Registers the stubs for the whole process; later calls are ignored.
+pub fn set_stubs(stubs: Stubs) {
+    let _ = STUBS.set(stubs);
+}
+
+pub fn invoke(instruction: &Instruction, account_infos: &[AccountInfo]) -> ProgramResult {
+    invoke_signed(instruction, account_infos, &[])
+}
+
+pub fn invoke_unchecked(instruction: &Instruction, account_infos: &[AccountInfo]) -> ProgramResult {
+    invoke_signed_unchecked(instruction, account_infos, &[])
+}
+
+pub fn invoke_signed(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed_unchecked(instruction, account_infos, signers_seeds)
+}
+
+pub fn invoke_signed_unchecked(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    match STUBS.get() {
+        Some(stubs) => (stubs.invoke_signed)(instruction, account_infos, signers_seeds),
+        None => Ok(()),
+    }
+}
+
+pub fn set_return_data(data: &[u8]) {
+    if let Some(stubs) = STUBS.get() {
+        (stubs.set_return_data)(data);
+    }
+}
+
+pub fn get_return_data() -> Option<(Pubkey, Vec<u8>)> {
+    STUBS.get().and_then(|stubs| (stubs.get_return_data)())
+}
@@ -0,0 +1,8 @@
+//! Stand-in for `solana-invoke`, which Anchor 0.32 uses for every CPI but which panics
+//! outside `target_os = "solana"`. `solana_program::program` has the same API and goes
+//! through the syscall stubs off-chain, so the program can run natively under
+//! `solana-program-test`. Only the `native-tests` workspace patches it in.
+
+pub use solana_program::program::{
+    invoke, invoke_signed, invoke_signed_unchecked, invoke_unchecked,
+};
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use solana_program::{ed25519_program, sysvar::instructions::get_instruction_relative};
+
+use crate::{errors::TapShieldErr, states::FaucetRegistry};
+
+/// claimer (32) | faucet (32) | amount (8, LE) | expiry (8, LE)
+pub const ATTESTATION_MESSAGE_LEN: usize = 80;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+
+/// Message an attester signs to approve `claimer` taking `amount` from `faucet` until `expiry`.
+pub fn attestation_message(
+    claimer: &Pubkey,
+    faucet: &Pubkey,
+    amount: u64,
+    expiry: i64,
+) -> [u8; ATTESTATION_MESSAGE_LEN] {
+    let mut message = [0u8; ATTESTATION_MESSAGE_LEN];
+
+    message[..32].copy_from_slice(claimer.as_ref());
+    message[32..64].copy_from_slice(faucet.as_ref());
+    message[64..72].copy_from_slice(&amount.to_le_bytes());
+    message[72..].copy_from_slice(&expiry.to_le_bytes());
+
+    message
+}
+
+/// Requires the instruction right before this one to be an ed25519 verification of an
+/// attestation signed by one of the faucet's attesters. No-op when the faucet has none.
+pub fn verify_attestation(
+    faucet_registry: &FaucetRegistry,
+    faucet_key: Pubkey,
+    instruction_sysvar: Option<&AccountInfo>,
+    claimer: Pubkey,
+    amount: u64,
+    curr_time: i64,
+) -> Result<()> {
+    if faucet_registry.attesters.is_empty() {
+        return Ok(());
+    }
+
+    let instruction_sysvar = instruction_sysvar.ok_or(TapShieldErr::AttestationMissing)?;
+
+    let ix = get_instruction_relative(-1, instruction_sysvar)
+        .map_err(|_| TapShieldErr::AttestationMissing)?;
+
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        TapShieldErr::AttestationMissing
+    );
+
+    require_eq!(ix.accounts.len(), 0, TapShieldErr::InvalidAttestation);
+
+    let (attester, message) = parse_ed25519_instruction(&ix.data)?;
+
+    require!(
+        faucet_registry.attesters.contains(&attester),
+        TapShieldErr::UnknownAttester
+    );
+
+    let expected = attestation_message(&claimer, &faucet_key, amount, 0);
+
+    require!(
+        message.len() == ATTESTATION_MESSAGE_LEN && message[..72] == expected[..72],
+        TapShieldErr::InvalidAttestation
+    );
+
+    let expiry = i64::from_le_bytes(message[72..].try_into().unwrap());
+
+    require!(expiry >= curr_time, TapShieldErr::AttestationExpired);
+
+    msg!("CLAIM ATTESTED BY {} UNTIL {}", attester, expiry);
+
+    Ok(())
+}
+
+/// Returns the signer and message of a single-signature ed25519 instruction whose
+/// offsets all point into its own data.
+fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, &[u8])> {
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN,
+        TapShieldErr::InvalidAttestation
+    );
+    require_eq!(data[0], 1, TapShieldErr::InvalidAttestation);
+
+    let offsets = &data[SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN];
+    let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+
+    let signature_offset = read_u16(0) as usize;
+    let signature_ix_index = read_u16(2);
+    let pubkey_offset = read_u16(4) as usize;
+    let pubkey_ix_index = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    let message_ix_index = read_u16(12);
+
+    require!(
+        signature_ix_index == u16::MAX
+            && pubkey_ix_index == u16::MAX
+            && message_ix_index == u16::MAX,
+        TapShieldErr::InvalidAttestation
+    );
+
+    require!(
+        data.len() >= signature_offset + SIGNATURE_LEN
+            && data.len() >= pubkey_offset + PUBKEY_LEN
+            && data.len() >= message_offset + message_size,
+        TapShieldErr::InvalidAttestation
+    );
+
+    let attester = Pubkey::try_from(&data[pubkey_offset..pubkey_offset + PUBKEY_LEN])
+        .map_err(|_| TapShieldErr::InvalidAttestation)?;
+
+    Ok((
+        attester,
+        &data[message_offset..message_offset + message_size],
+    ))
+}
@@ -34,4 +34,141 @@ pub enum TapShieldErr {
 
     #[msg("Faucet name exceed 32 characters")]
     FaucetNameTooLong,
+
+    #[msg("Network-wide cooldown still active")]
+    GlobalCooldownActive,
+
+    #[msg("Invalid claim quota")]
+    InvalidQuota,
+
+    #[msg("Claim count quota exceeded for the current window")]
+    ClaimQuotaExceeded,
+
+    #[msg("Claim amount quota exceeded for the current window")]
+    AmountQuotaExceeded,
+
+    #[msg("Insufficient vault balance")]
+    InsufficientVaultBalance,
+
+    #[msg("Faucet already bound to a mint")]
+    FaucetMintAlreadyBound,
+
+    #[msg("Mint does not match the faucet")]
+    FaucetMintMismatch,
+
+    #[msg("Faucet requires the claimer to sign")]
+    ClaimerSignatureRequired,
+
+    #[msg("Too many attesters")]
+    TooManyAttesters,
+
+    #[msg("Missing ed25519 attestation instruction")]
+    AttestationMissing,
+
+    #[msg("Invalid attestation")]
+    InvalidAttestation,
+
+    #[msg("Attestation signed by an unknown attester")]
+    UnknownAttester,
+
+    #[msg("Attestation expired")]
+    AttestationExpired,
+
+    #[msg("Too many recorders")]
+    TooManyRecorders,
+
+    #[msg("Recorder already exists")]
+    RecorderAlreadyExists,
+
+    #[msg("Recorder not found")]
+    RecorderNotFound,
+
+    #[msg("Signer is not the pending owner")]
+    NotPendingOwner,
+
+    #[msg("Wallet is blocked")]
+    WalletBlocked,
+
+    #[msg("Wallet is not on the faucet allowlist")]
+    WalletNotAllowlisted,
+
+    #[msg("Reason exceeds 64 characters")]
+    ReasonTooLong,
+
+    #[msg("Claim record does not belong to this faucet and claimer")]
+    InvalidClaimRecord,
+
+    #[msg("Claim record is still inside the active cooldown window")]
+    ClaimRecordStillActive,
+
+    #[msg("Invalid budget: amount and period must be positive")]
+    InvalidBudget,
+
+    #[msg("Faucet budget for the current period is spent")]
+    BudgetExhausted,
+
+    #[msg("Caller is not the program bound to this faucet")]
+    UnboundCaller,
+
+    #[msg("Faucet is paused")]
+    FaucetPaused,
+
+    #[msg("Faucet vault still holds funds")]
+    FaucetVaultNotEmpty,
+
+    #[msg("Metadata field exceeds its maximum length")]
+    MetadataTooLong,
+
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+
+    #[msg("Account layout version is not supported")]
+    UnsupportedAccountVersion,
+
+    #[msg("Faucet requires a larger claimer bond")]
+    BondRequired,
+
+    #[msg("Bond has pending slash reports or is still in its lock period")]
+    BondLocked,
+
+    #[msg("Bond balance is too low")]
+    InsufficientBond,
+
+    #[msg("A wallet in the claimer's cluster claimed too recently")]
+    ClusterCooldownActive,
+
+    #[msg("Wallet cluster does not match the claimer's cluster membership")]
+    ClusterMismatch,
+
+    #[msg("Claimer reputation is below the faucet minimum")]
+    ReputationTooLow,
+
+    #[msg("Claim amount exceeds the limit for the claimer's reputation")]
+    ReputationAmountExceeded,
+
+    #[msg("Remaining accounts do not match the batched claims")]
+    InvalidBatchAccounts,
+
+    #[msg(
+        "Claims must go to the faucet's claim log if it has one, and to a claim record otherwise"
+    )]
+    ClaimLogMismatch,
+
+    #[msg("A mint can only be bound before the faucet's first claim")]
+    FaucetHasClaims,
+
+    #[msg("Faucet is not approved by the protocol admin to change reputation")]
+    ReputationNotApproved,
+
+    #[msg("Faucet already flagged this claimer the maximum number of times")]
+    FlagLimitReached,
+
+    #[msg("Faucet has slash reports waiting for the admin")]
+    FaucetHasPendingReports,
+
+    #[msg("Faucet still has open claim records; close them first")]
+    FaucetHasOpenClaimRecords,
+
+    #[msg("Faucet does not require a bond, so it cannot report claimers")]
+    BondNotRequired,
 }
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{ClaimBudget, ClaimQuota, FaucetRegistry, ReputationFlag, ReputationPolicy};
+
+#[event]
+pub struct FaucetRegistered {
+    pub faucet: Pubkey,
+    pub faucet_id: Pubkey,
+    pub owner: Pubkey,
+    pub name: String,
+    pub cooldown_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FaucetMetadataUpdated {
+    pub faucet: Pubkey,
+    pub name: String,
+    pub uri: String,
+    pub category: String,
+    pub contact: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimRecorded {
+    pub faucet: Pubkey,
+    pub claimer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub faucet_total_claims: u64,
+    pub user_total_claims: u64,
+}
+
+/// Emitted right before a claim check fails. The transaction still reverts, but its logs
+/// (and therefore this event) are kept by the cluster.
+#[event]
+pub struct ClaimRejected {
+    pub faucet: Pubkey,
+    pub claimer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub error_code: u32,
+}
+
+/// Emitted when the owner proposes a new owner, or cancels the proposal (`pending_owner: None`).
+#[event]
+pub struct OwnershipTransferProposed {
+    pub faucet: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when the pending owner accepts and becomes the faucet owner.
+#[event]
+pub struct OwnershipTransferred {
+    pub faucet: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the protocol admin approves a slash report against a claimer's bond.
+#[event]
+pub struct BondSlashed {
+    pub faucet: Pubkey,
+    pub claimer: Pubkey,
+    pub reporter: Pubkey,
+    pub amount: u64,
+    pub remaining_bond: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an operator flags a claimer, lowering their reputation score.
+#[event]
+pub struct ReputationChanged {
+    pub faucet: Pubkey,
+    pub claimer: Pubkey,
+    pub flag: ReputationFlag,
+    pub old_score: u16,
+    pub new_score: u16,
+    pub timestamp: i64,
+}
+
+/// Snapshot of every claim policy knob, emitted whenever one of them changes.
+#[event]
+pub struct PolicyUpdated {
+    pub faucet: Pubkey,
+    pub cooldown_seconds: i64,
+    pub global_cooldown_override: Option<i64>,
+    pub claim_quota: Option<ClaimQuota>,
+    pub claim_budget: Option<ClaimBudget>,
+    pub require_claimer_signature: bool,
+    pub allowlist_only: bool,
+    pub paused: bool,
+    pub required_bond: u64,
+    pub reputation_policy: Option<ReputationPolicy>,
+    pub reputation_approved: bool,
+    pub registry_approved: bool,
+    pub timestamp: i64,
+}
+
+impl PolicyUpdated {
+    pub fn new(faucet: Pubkey, faucet_registry: &FaucetRegistry, timestamp: i64) -> Self {
+        Self {
+            faucet,
+            cooldown_seconds: faucet_registry.cooldown_seconds,
+            global_cooldown_override: faucet_registry.global_cooldown_override,
+            claim_quota: faucet_registry.claim_quota,
+            claim_budget: faucet_registry.claim_budget,
+            require_claimer_signature: faucet_registry.require_claimer_signature,
+            allowlist_only: faucet_registry.allowlist_only,
+            paused: faucet_registry.paused,
+            required_bond: faucet_registry.required_bond,
+            reputation_policy: faucet_registry.reputation_policy,
+            reputation_approved: faucet_registry.reputation_approved,
+            registry_approved: faucet_registry.registry_approved,
+            timestamp,
+        }
+    }
+}
@@ -0,0 +1,687 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+    Discriminator,
+};
+
+use crate::{
+    attestation::verify_attestation,
+    errors::TapShieldErr,
+    events::{ClaimRecorded, ClaimRejected},
+    states::{
+        ClaimLog, ClaimLogEntry, ClaimRecord, ClaimerBond, ClusterMembership, FaucetRegistry,
+        ListKind, ProtocolConfig, UserClaimRegistry, UserFaucetState, WalletCluster,
+        WalletListEntry, WELL_SPACED_CLAIM_REWARD,
+    },
+};
+
+/// The accounts `run_claim` works on, borrowed from the `Accounts` struct of the claim
+/// instruction running it.
+pub struct ClaimAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub claimer: &'a AccountInfo<'info>,
+    pub faucet_registry: &'a mut Account<'info, FaucetRegistry>,
+    pub protocol_config: &'a ProtocolConfig,
+    pub claim_record: Option<&'a mut ClaimRecord>,
+    pub user_claim_registry: &'a AccountInfo<'info>,
+    pub user_claim_registry_bump: u8,
+    pub user_faucet_state: &'a mut UserFaucetState,
+    pub user_faucet_state_bump: u8,
+    pub protocol_block_entry: &'a AccountInfo<'info>,
+    pub faucet_list_entry: &'a AccountInfo<'info>,
+    pub claimer_bond: &'a AccountInfo<'info>,
+    pub cluster_membership: &'a AccountInfo<'info>,
+    pub wallet_cluster: Option<&'a mut Account<'info, WalletCluster>>,
+    pub instruction_sysvar: Option<&'a AccountInfo<'info>>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub claim_log: Option<&'a AccountLoader<'info, ClaimLog>>,
+}
+
+/// Checks and records one claim: every claim instruction goes through here, and only pays
+/// out (if it does) once this has returned `Ok`. The claimer's `UserClaimRegistry` is shared
+/// by all faucets, so unsigned claims only update it at faucets the admin approved; otherwise
+/// any faucet could record claims in a wallet's name and put it on cooldown everywhere.
+pub fn run_claim(accounts: ClaimAccounts, amount: u64) -> Result<()> {
+    let ClaimAccounts {
+        payer,
+        claimer,
+        faucet_registry,
+        protocol_config,
+        claim_record,
+        user_claim_registry: user_claim_registry_info,
+        user_claim_registry_bump,
+        user_faucet_state,
+        user_faucet_state_bump,
+        protocol_block_entry,
+        faucet_list_entry,
+        claimer_bond,
+        cluster_membership,
+        wallet_cluster,
+        instruction_sysvar,
+        system_program,
+        claim_log,
+    } = accounts;
+
+    let clock = Clock::get()?;
+    let curr_time = clock.unix_timestamp;
+
+    let faucet_key = faucet_registry.key();
+    let claimer_key = claimer.key();
+
+    let mut user_claim_registry = load_or_empty::<UserClaimRegistry>(user_claim_registry_info)?;
+
+    report_rejection(faucet_key, claimer_key, amount, curr_time, || {
+        verify_attestation(
+            faucet_registry,
+            faucet_key,
+            instruction_sysvar,
+            claimer_key,
+            amount,
+            curr_time,
+        )?;
+
+        check_wallet_lists(
+            faucet_registry,
+            protocol_block_entry,
+            faucet_list_entry,
+            curr_time,
+        )?;
+
+        check_bond(faucet_registry, claimer_bond, claimer.is_signer)?;
+
+        check_cluster(
+            faucet_registry,
+            cluster_membership,
+            wallet_cluster.as_deref(),
+            curr_time,
+        )?;
+
+        check_claim(
+            faucet_registry,
+            protocol_config,
+            &user_claim_registry,
+            user_faucet_state,
+            amount,
+            curr_time,
+        )?;
+
+        consume_budget(faucet_registry, amount, &clock)
+    })?;
+
+    if claimer.is_signer || faucet_registry.registry_approved {
+        commit_user_claim_registry(
+            &mut user_claim_registry,
+            user_claim_registry_bump,
+            faucet_registry,
+            faucet_key,
+            claimer_key,
+            curr_time,
+        );
+
+        write_pda(
+            user_claim_registry_info,
+            &user_claim_registry,
+            UserClaimRegistry::DISCRIMINATOR.len() + UserClaimRegistry::INIT_SPACE,
+            &[
+                b"user_registry",
+                claimer_key.as_ref(),
+                &[user_claim_registry_bump],
+            ],
+            payer,
+            system_program,
+        )?;
+    }
+
+    commit_claim(
+        faucet_registry,
+        faucet_key,
+        claim_record,
+        claim_log,
+        user_claim_registry.total_claims_across_faucets,
+        claimer_key,
+        amount,
+        curr_time,
+    )?;
+
+    commit_user_faucet_state(
+        faucet_registry,
+        faucet_key,
+        user_faucet_state,
+        claimer_key,
+        user_faucet_state_bump,
+        amount,
+        curr_time,
+    );
+
+    commit_cluster(wallet_cluster.map(|cluster| &mut **cluster), curr_time);
+
+    Ok(())
+}
+
+/// Rejects protocol-banned wallets, wallets banned by this faucet and, for allowlist-only
+/// faucets, wallets without an active allowlist entry. Both entries are optional PDAs,
+/// so an account that was never created simply means "no entry".
+pub fn check_wallet_lists(
+    faucet_registry: &FaucetRegistry,
+    protocol_block_entry: &AccountInfo,
+    faucet_list_entry: &AccountInfo,
+    curr_time: i64,
+) -> Result<()> {
+    if let Some(entry) = load_optional::<WalletListEntry>(protocol_block_entry)? {
+        require!(!entry.is_active(curr_time), TapShieldErr::WalletBlocked);
+    }
+
+    let faucet_entry = load_optional::<WalletListEntry>(faucet_list_entry)?
+        .filter(|entry| entry.is_active(curr_time));
+
+    match faucet_entry.map(|entry| entry.kind) {
+        Some(ListKind::Blocked) => err!(TapShieldErr::WalletBlocked),
+        Some(ListKind::Allowed) => Ok(()),
+        None if faucet_registry.allowlist_only => err!(TapShieldErr::WalletNotAllowlisted),
+        None => Ok(()),
+    }
+}
+
+/// Deserializes an optional PDA; `None` if it was never created (or has been closed).
+pub fn load_optional<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = info.try_borrow_data()?;
+
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+/// Like `load_optional`, but a missing account reads as the zeroed state `init_if_needed`
+/// hands the claim instructions.
+pub fn load_or_empty<T: AccountDeserialize + Discriminator + Space>(
+    info: &AccountInfo,
+) -> Result<T> {
+    match load_optional(info)? {
+        Some(account) => Ok(account),
+        None => {
+            let data = vec![0; T::DISCRIMINATOR.len() + T::INIT_SPACE];
+
+            T::try_deserialize_unchecked(&mut &data[..])
+        }
+    }
+}
+
+/// For faucets with a `required_bond`, the claimer's bond PDA must exist and hold at least
+/// that many lamports. Bonded claims must be signed by the claimer, as signed claims always
+/// reach the registry whose last claim keeps the bond locked.
+pub fn check_bond(
+    faucet_registry: &FaucetRegistry,
+    claimer_bond: &AccountInfo,
+    claimer_signed: bool,
+) -> Result<()> {
+    if faucet_registry.required_bond == 0 {
+        return Ok(());
+    }
+
+    require!(claimer_signed, TapShieldErr::ClaimerSignatureRequired);
+
+    let bond = load_optional::<ClaimerBond>(claimer_bond)?.ok_or(TapShieldErr::BondRequired)?;
+
+    require!(
+        bond.amount >= faucet_registry.required_bond,
+        TapShieldErr::BondRequired
+    );
+
+    Ok(())
+}
+
+/// The claimer's cluster, checked against their membership: a linked claimer must pass
+/// their cluster as `wallet_cluster`; an unlinked one must not pass any.
+pub fn verified_cluster<'a, 'info>(
+    cluster_membership: &AccountInfo,
+    wallet_cluster: Option<&'a Account<'info, WalletCluster>>,
+) -> Result<Option<&'a Account<'info, WalletCluster>>> {
+    let membership = load_optional::<ClusterMembership>(cluster_membership)?;
+
+    match (membership, wallet_cluster) {
+        (None, None) => Ok(None),
+        (Some(membership), Some(wallet_cluster)) if membership.cluster == wallet_cluster.key() => {
+            Ok(Some(wallet_cluster))
+        }
+        _ => err!(TapShieldErr::ClusterMismatch),
+    }
+}
+
+/// Wallets linked into a cluster share the faucet cooldown: the claimer has to wait it out
+/// since the most recent claim of any wallet in their cluster.
+pub fn check_cluster(
+    faucet_registry: &FaucetRegistry,
+    cluster_membership: &AccountInfo,
+    wallet_cluster: Option<&Account<WalletCluster>>,
+    curr_time: i64,
+) -> Result<()> {
+    let Some(wallet_cluster) = verified_cluster(cluster_membership, wallet_cluster)? else {
+        return Ok(());
+    };
+
+    if let Some(last_claim_timestamp) = wallet_cluster.last_claim_timestamp {
+        let time_since_last_cluster_claim = curr_time
+            .checked_sub(last_claim_timestamp)
+            .ok_or(TapShieldErr::InvalidTimestamp)?;
+
+        require!(
+            time_since_last_cluster_claim >= faucet_registry.cooldown_seconds,
+            TapShieldErr::ClusterCooldownActive
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs `checks` and, if one fails, emits `ClaimRejected` with its error code before
+/// propagating the error.
+pub fn report_rejection(
+    faucet: Pubkey,
+    claimer: Pubkey,
+    amount: u64,
+    curr_time: i64,
+    checks: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    checks().inspect_err(|error| {
+        emit!(ClaimRejected {
+            faucet,
+            claimer,
+            amount,
+            timestamp: curr_time,
+            error_code: error_code(error),
+        });
+    })
+}
+
+/// Custom error code of `error`, `0` for errors raised outside Anchor.
+pub fn error_code(error: &Error) -> u32 {
+    match error {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(_) => 0,
+    }
+}
+
+/// Cooldown and quota checks shared by every instruction that records a claim. The global
+/// cooldown runs from the claimer's last claim anywhere, the faucet cooldown and the quota
+/// window from their claims at this faucet.
+pub fn check_claim(
+    faucet_registry: &FaucetRegistry,
+    protocol_config: &ProtocolConfig,
+    user_claim_registry: &UserClaimRegistry,
+    user_faucet_state: &UserFaucetState,
+    amount: u64,
+    curr_time: i64,
+) -> Result<()> {
+    let cooldown_second = faucet_registry.cooldown_seconds;
+    let global_cooldown = faucet_registry.global_cooldown(protocol_config);
+
+    require!(!faucet_registry.paused, TapShieldErr::FaucetPaused);
+    require!(amount > 0, TapShieldErr::InvalidInput);
+    require!(cooldown_second > 0, TapShieldErr::InvalidCooldown);
+
+    if user_claim_registry.user != Pubkey::default() {
+        let time_since_last_claim = curr_time
+            .checked_sub(user_claim_registry.last_claim_timestamp)
+            .ok_or(TapShieldErr::InvalidTimestamp)?;
+
+        require!(
+            time_since_last_claim >= global_cooldown,
+            TapShieldErr::GlobalCooldownActive
+        );
+
+        msg!(
+            "GLOBAL COOLDOWN HAS BEEN PASSED. {} SECONDS SINCE LAST CLAIM",
+            time_since_last_claim
+        );
+    } else {
+        msg!("FIRST TIME CLAIMER ? ELIGIBLE");
+    }
+
+    if user_faucet_state.user != Pubkey::default() {
+        let time_since_last_faucet_claim = curr_time
+            .checked_sub(user_faucet_state.last_claim_timestamp)
+            .ok_or(TapShieldErr::InvalidTimestamp)?;
+
+        require!(
+            time_since_last_faucet_claim >= cooldown_second,
+            TapShieldErr::ClaimTooRecent
+        );
+
+        msg!(
+            "COOLDOWN HAS BEEN PASSED. {} SECONDS SINCE LAST CLAIM AT THIS FAUCET",
+            time_since_last_faucet_claim
+        );
+    }
+
+    if let Some(quota) = faucet_registry.claim_quota {
+        let (claims_in_window, amount_in_window) =
+            user_faucet_state.window_usage(curr_time, quota.window_seconds);
+
+        require!(
+            claims_in_window < quota.max_claims,
+            TapShieldErr::ClaimQuotaExceeded
+        );
+
+        if quota.max_amount > 0 {
+            let total_amount = amount_in_window
+                .checked_add(amount)
+                .ok_or(TapShieldErr::AmountQuotaExceeded)?;
+
+            require!(
+                total_amount <= quota.max_amount,
+                TapShieldErr::AmountQuotaExceeded
+            );
+        }
+    }
+
+    if let Some(policy) = faucet_registry.reputation_policy {
+        let score = user_claim_registry.reputation();
+
+        require!(score >= policy.min_score, TapShieldErr::ReputationTooLow);
+
+        if let Some(max_amount) = policy.max_amount_for(score) {
+            require!(amount <= max_amount, TapShieldErr::ReputationAmountExceeded);
+        }
+    }
+
+    Ok(())
+}
+
+/// Charges `amount` against the faucet's disbursement budget, starting a fresh period
+/// once the clock has moved past the stored one.
+pub fn consume_budget(
+    faucet_registry: &mut FaucetRegistry,
+    amount: u64,
+    clock: &Clock,
+) -> Result<()> {
+    let Some(budget) = faucet_registry.claim_budget else {
+        return Ok(());
+    };
+
+    let period = budget.current_period(clock);
+
+    if period != faucet_registry.budget_period {
+        faucet_registry.budget_period = period;
+        faucet_registry.budget_spent = 0;
+    }
+
+    let spent = faucet_registry
+        .budget_spent
+        .checked_add(amount)
+        .ok_or(TapShieldErr::BudgetExhausted)?;
+
+    require!(spent <= budget.max_amount, TapShieldErr::BudgetExhausted);
+
+    faucet_registry.budget_spent = spent;
+
+    Ok(())
+}
+
+/// Writes the claim record, or appends to the claim log for faucets that have one, and
+/// bumps the faucet counters once `check_claim` has passed.
+#[allow(clippy::too_many_arguments)]
+pub fn commit_claim(
+    faucet_registry: &mut FaucetRegistry,
+    faucet_key: Pubkey,
+    claim: Option<&mut ClaimRecord>,
+    claim_log: Option<&AccountLoader<ClaimLog>>,
+    user_total_claims: u64,
+    claimer_pubkey: Pubkey,
+    amount: u64,
+    curr_time: i64,
+) -> Result<()> {
+    match claim {
+        Some(claim) => {
+            require!(
+                faucet_registry.claim_log.is_none(),
+                TapShieldErr::ClaimLogMismatch
+            );
+
+            claim.version = ClaimRecord::VERSION;
+            claim.claimer = claimer_pubkey;
+            claim.faucet_id = faucet_key;
+            claim.amount = amount;
+            claim.timestamp = curr_time;
+
+            faucet_registry.open_claim_records += 1;
+        }
+        None => require!(
+            faucet_registry.claim_log.is_some(),
+            TapShieldErr::ClaimLogMismatch
+        ),
+    }
+
+    append_claim_log(
+        faucet_registry,
+        claim_log,
+        claimer_pubkey,
+        amount,
+        curr_time,
+    )?;
+
+    commit_counters(
+        faucet_registry,
+        faucet_key,
+        user_total_claims,
+        claimer_pubkey,
+        amount,
+        curr_time,
+    );
+
+    Ok(())
+}
+
+/// Appends the claim to the faucet's claim log; no-op for faucets without one.
+pub fn append_claim_log(
+    faucet_registry: &FaucetRegistry,
+    claim_log: Option<&AccountLoader<ClaimLog>>,
+    claimer_pubkey: Pubkey,
+    amount: u64,
+    curr_time: i64,
+) -> Result<()> {
+    let Some(claim_log_key) = faucet_registry.claim_log else {
+        return Ok(());
+    };
+
+    let claim_log = claim_log
+        .filter(|claim_log| claim_log.key() == claim_log_key)
+        .ok_or(TapShieldErr::ClaimLogMismatch)?;
+
+    claim_log.load_mut()?.push(ClaimLogEntry {
+        claimer: claimer_pubkey,
+        amount,
+        timestamp: curr_time,
+    });
+
+    Ok(())
+}
+
+/// Moves the claimer's cross-faucet state forward; only called for claims the claimer
+/// signed. At a reputation-approved faucet, a claim that comes at least twice the faucet
+/// cooldown after the claimer's previous one earns them `WELL_SPACED_CLAIM_REWARD` reputation.
+pub fn commit_user_claim_registry(
+    user_claim_registry: &mut UserClaimRegistry,
+    user_registry_bump: u8,
+    faucet_registry: &FaucetRegistry,
+    faucet_key: Pubkey,
+    claimer_pubkey: Pubkey,
+    curr_time: i64,
+) {
+    let well_spaced = faucet_registry.reputation_approved
+        && user_claim_registry.total_claims_across_faucets > 0
+        && curr_time.saturating_sub(user_claim_registry.last_claim_timestamp)
+            >= faucet_registry.cooldown_seconds.saturating_mul(2);
+
+    if well_spaced {
+        user_claim_registry.adjust_reputation(WELL_SPACED_CLAIM_REWARD as i32);
+    }
+
+    user_claim_registry.version = UserClaimRegistry::VERSION;
+    user_claim_registry.user = claimer_pubkey;
+    user_claim_registry.bump = user_registry_bump;
+    user_claim_registry.last_claim_timestamp = curr_time;
+    user_claim_registry.last_faucet = faucet_key;
+
+    user_claim_registry.total_claims_across_faucets += 1;
+}
+
+/// Rejects paying `amount` out of a lamport vault unless it leaves the vault empty or still
+/// rent-exempt; the system program would refuse any balance in between.
+pub fn check_vault_payout(vault: &AccountInfo, amount: u64) -> Result<()> {
+    let remaining = vault
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(TapShieldErr::InsufficientVaultBalance)?;
+
+    require!(
+        remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+        TapShieldErr::InsufficientVaultBalance
+    );
+
+    Ok(())
+}
+
+/// The part of `commit_claim` that doesn't need a claim record, used directly by batches.
+/// `user_total_claims` is the claimer's `total_claims_across_faucets`, reported in the event.
+pub fn commit_counters(
+    faucet_registry: &mut FaucetRegistry,
+    faucet_key: Pubkey,
+    user_total_claims: u64,
+    claimer_pubkey: Pubkey,
+    amount: u64,
+    curr_time: i64,
+) {
+    faucet_registry.total_claims += 1;
+    faucet_registry.total_amount = faucet_registry.total_amount.saturating_add(amount);
+
+    msg!(
+        "CLAIM RECORD FROM: {}, CLAIMED {} FROM {}",
+        claimer_pubkey,
+        amount,
+        faucet_registry.name
+    );
+
+    emit!(ClaimRecorded {
+        faucet: faucet_key,
+        claimer: claimer_pubkey,
+        amount,
+        timestamp: curr_time,
+        faucet_total_claims: faucet_registry.total_claims,
+        user_total_claims,
+    });
+}
+
+/// Updates the claimer's per-faucet state, counting them towards `unique_claimers` the
+/// first time they claim from this faucet.
+pub fn commit_user_faucet_state(
+    faucet_registry: &mut FaucetRegistry,
+    faucet_key: Pubkey,
+    user_faucet_state: &mut UserFaucetState,
+    claimer_pubkey: Pubkey,
+    bump: u8,
+    amount: u64,
+    curr_time: i64,
+) {
+    if user_faucet_state.user == Pubkey::default() {
+        user_faucet_state.version = UserFaucetState::VERSION;
+        user_faucet_state.user = claimer_pubkey;
+        user_faucet_state.faucet = faucet_key;
+        user_faucet_state.first_claim_timestamp = curr_time;
+        user_faucet_state.bump = bump;
+
+        faucet_registry.unique_claimers += 1;
+    }
+
+    user_faucet_state.last_claim_timestamp = curr_time;
+    user_faucet_state.claim_count += 1;
+    user_faucet_state.total_amount = user_faucet_state.total_amount.saturating_add(amount);
+    user_faucet_state.push_recent_claim(curr_time, amount);
+}
+
+/// Moves the cluster's last claim forward once `check_cluster` has accepted it.
+pub fn commit_cluster(wallet_cluster: Option<&mut WalletCluster>, curr_time: i64) {
+    if let Some(wallet_cluster) = wallet_cluster {
+        wallet_cluster.last_claim_timestamp = Some(curr_time);
+        wallet_cluster.total_claims += 1;
+    }
+}
+
+/// Serializes `account` into the PDA at `info`, first creating it (paid by `payer`) if it
+/// doesn't exist yet.
+pub fn write_pda<'info, T: AccountSerialize>(
+    info: &AccountInfo<'info>,
+    account: &T,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if info.owner != &crate::ID {
+        create_pda(info, space, signer_seeds, payer, system_program)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+
+    account.try_serialize(&mut &mut data[..])
+}
+
+/// Same steps as Anchor's `init`, including for PDAs someone already sent lamports to.
+fn create_pda<'info>(
+    info: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer_seeds = &[signer_seeds];
+
+    if info.lamports() == 0 {
+        let cpi_ctx = CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: info.clone(),
+            },
+            signer_seeds,
+        );
+
+        return create_account(cpi_ctx, rent, space as u64, &crate::ID);
+    }
+
+    let top_up = rent.saturating_sub(info.lamports());
+
+    if top_up > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: info.clone(),
+            },
+        );
+
+        transfer(cpi_ctx, top_up)?;
+    }
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        system_program.clone(),
+        Allocate {
+            account_to_allocate: info.clone(),
+        },
+        signer_seeds,
+    );
+    allocate(cpi_ctx, space as u64)?;
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        system_program.clone(),
+        Assign {
+            account_to_assign: info.clone(),
+        },
+        signer_seeds,
+    );
+    assign(cpi_ctx, &crate::ID)
+}
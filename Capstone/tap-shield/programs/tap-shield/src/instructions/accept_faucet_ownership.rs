@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, events::OwnershipTransferred, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct AcceptFaucetOwnership<'info> {
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.pending_owner == Some(new_owner.key()) @ TapShieldErr::NotPendingOwner
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> AcceptFaucetOwnership<'info> {
+    pub fn accept_faucet_ownership(&mut self) -> Result<()> {
+        let faucet_registry = &mut self.faucet_registry;
+        let previous = faucet_registry.owner;
+
+        faucet_registry.owner = self.new_owner.key();
+        faucet_registry.pending_owner = None;
+
+        msg!(
+            "FAUCET: {} OWNERSHIP TRANSFERRED FROM {} TO {}",
+            faucet_registry.name,
+            previous,
+            faucet_registry.owner
+        );
+
+        emit!(OwnershipTransferred {
+            faucet: faucet_registry.key(),
+            previous_owner: previous,
+            new_owner: faucet_registry.owner,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{ListKind, ProtocolConfig, WalletListEntry, MAX_REASON_LEN},
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddProtocolBlock<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump,
+        has_one = admin @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = WalletListEntry::DISCRIMINATOR.len() + WalletListEntry::INIT_SPACE,
+        seeds = [b"block", wallet.as_ref()],
+        bump
+    )]
+    pub block_entry: Account<'info, WalletListEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddProtocolBlock<'info> {
+    pub fn add_protocol_block(
+        &mut self,
+        wallet: Pubkey,
+        reason: String,
+        expires_at: Option<i64>,
+        bumps: &AddProtocolBlockBumps,
+    ) -> Result<()> {
+        require!(reason.len() <= MAX_REASON_LEN, TapShieldErr::ReasonTooLong);
+
+        self.block_entry.set_inner(WalletListEntry {
+            version: WalletListEntry::VERSION,
+            wallet,
+            faucet: None,
+            kind: ListKind::Blocked,
+            reason,
+            expires_at,
+            added_by: self.admin.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            bump: bumps.block_entry,
+            reserved: [0; 16],
+        });
+
+        msg!(
+            "WALLET {} BLOCKED NETWORK-WIDE UNTIL {:?}",
+            wallet,
+            expires_at
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{FaucetRegistry, MAX_RECORDERS},
+};
+
+#[derive(Accounts)]
+pub struct AddRecorder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> AddRecorder<'info> {
+    pub fn add_recorder(&mut self, recorder: Pubkey) -> Result<()> {
+        let faucet_registry = &mut self.faucet_registry;
+
+        require!(
+            !faucet_registry.recorders.contains(&recorder),
+            TapShieldErr::RecorderAlreadyExists
+        );
+        require!(
+            faucet_registry.recorders.len() < MAX_RECORDERS,
+            TapShieldErr::TooManyRecorders
+        );
+
+        faucet_registry.recorders.push(recorder);
+
+        msg!(
+            "FAUCET: {} ADDED RECORDER {}",
+            faucet_registry.name,
+            recorder
+        );
+
+        Ok(())
+    }
+}
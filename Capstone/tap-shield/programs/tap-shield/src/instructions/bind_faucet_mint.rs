@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{errors::TapShieldErr, states::FaucetRegistry};
+
+/// Only allowed before the first claim, so the faucet's amounts (quota window, budget,
+/// totals) are all in one unit: lamports before binding, the mint's base units after.
+#[derive(Accounts)]
+pub struct BindFaucetMint<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet,
+        constraint = faucet_registry.mint.is_none() @ TapShieldErr::FaucetMintAlreadyBound,
+        constraint = faucet_registry.total_claims == 0 @ TapShieldErr::FaucetHasClaims
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        seeds = [b"vault", faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BindFaucetMint<'info> {
+    pub fn bind_faucet_mint(&mut self) -> Result<()> {
+        self.faucet_registry.mint = Some(self.mint.key());
+
+        msg!(
+            "FAUCET: {} BOUND TO MINT {}",
+            self.faucet_registry.name,
+            self.mint.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    instructions::CPI_AUTHORITY_SEED,
+    states::{FaucetRegistry, ProgramBinding},
+};
+
+#[derive(Accounts)]
+pub struct BindFaucetProgram<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> BindFaucetProgram<'info> {
+    /// `None` unbinds the faucet so `check_and_record` rejects every caller.
+    pub fn bind_faucet_program(&mut self, program_id: Option<Pubkey>) -> Result<()> {
+        let program_binding = program_id.map(|program_id| {
+            let (authority, _) = Pubkey::find_program_address(&[CPI_AUTHORITY_SEED], &program_id);
+
+            ProgramBinding {
+                program_id,
+                authority,
+            }
+        });
+
+        self.faucet_registry.program_binding = program_binding;
+
+        msg!(
+            "FAUCET: {} BOUND TO PROGRAM {:?}",
+            self.faucet_registry.name,
+            program_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    guard::{run_claim, ClaimAccounts},
+    states::{
+        ClaimLog, ClaimRecord, FaucetRegistry, ProtocolConfig, UserFaucetState, WalletCluster,
+    },
+};
+
+/// Seed of the PDA a bound program signs with when calling `check_and_record`.
+pub const CPI_AUTHORITY_SEED: &[u8] = b"tap_shield_authority";
+
+/// Claim check meant to be invoked via CPI from the faucet's own program. The caller proves
+/// its identity by signing with its `[CPI_AUTHORITY_SEED]` PDA, and any failed check aborts
+/// the caller's whole instruction.
+#[derive(Accounts)]
+pub struct CheckAndRecord<'info> {
+    pub caller_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    ///CHECK: only used as PDA seed and signer flag
+    pub claimer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.program_binding.is_some_and(|binding| binding.authority == caller_authority.key()) @ TapShieldErr::UnboundCaller,
+        constraint = !faucet_registry.require_claimer_signature || claimer.is_signer @ TapShieldErr::ClaimerSignatureRequired
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ClaimRecord::DISCRIMINATOR.len() + ClaimRecord::INIT_SPACE,
+        seeds = [b"claim", claimer.key().as_ref(), faucet_registry.key().as_ref(), &faucet_registry.total_claims.to_le_bytes()],
+        bump
+    )]
+    pub claim_record: Option<Account<'info, ClaimRecord>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_registry", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer registry, may not exist; only written for signed claims or at registry-approved faucets
+    pub user_claim_registry: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserFaucetState::DISCRIMINATOR.len() + UserFaucetState::INIT_SPACE,
+        seeds = [b"user_faucet", claimer.key().as_ref(), faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub user_faucet_state: Account<'info, UserFaucetState>,
+
+    #[account(
+        seeds = [b"block", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: protocol blocklist entry, may not exist
+    pub protocol_block_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"list", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: faucet block/allowlist entry, may not exist
+    pub faucet_list_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"bond", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer bond, may not exist
+    pub claimer_bond: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"cluster_member", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: cluster membership, may not exist
+    pub cluster_membership: UncheckedAccount<'info>,
+
+    /// The claimer's cluster, required when they have a cluster membership.
+    #[account(mut)]
+    pub wallet_cluster: Option<Account<'info, WalletCluster>>,
+
+    #[account(
+        address = solana_program::sysvar::instructions::ID
+    )]
+    ///CHECK: only required when the faucet has attesters
+    pub instruction_sysvar: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The faucet's claim log, required when it has one; the claim record is left out then.
+    #[account(mut)]
+    pub claim_log: Option<AccountLoader<'info, ClaimLog>>,
+}
+
+impl<'info> CheckAndRecord<'info> {
+    pub fn check_and_record(&mut self, amount: u64, bumps: &CheckAndRecordBumps) -> Result<()> {
+        run_claim(
+            ClaimAccounts {
+                payer: &self.payer,
+                claimer: &self.claimer,
+                faucet_registry: &mut self.faucet_registry,
+                protocol_config: &self.protocol_config,
+                claim_record: self.claim_record.as_deref_mut(),
+                user_claim_registry: &self.user_claim_registry,
+                user_claim_registry_bump: bumps.user_claim_registry,
+                user_faucet_state: &mut self.user_faucet_state,
+                user_faucet_state_bump: bumps.user_faucet_state,
+                protocol_block_entry: &self.protocol_block_entry,
+                faucet_list_entry: &self.faucet_list_entry,
+                claimer_bond: &self.claimer_bond,
+                cluster_membership: &self.cluster_membership,
+                wallet_cluster: self.wallet_cluster.as_mut(),
+                instruction_sysvar: self.instruction_sysvar.as_deref(),
+                system_program: &self.system_program,
+                claim_log: self.claim_log.as_ref(),
+            },
+            amount,
+        )
+    }
+}
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    guard::{
+        check_bond, check_claim, check_cluster, check_wallet_lists, consume_budget, error_code,
+        load_or_empty, verified_cluster,
+    },
+    states::{FaucetRegistry, ProtocolConfig, UserClaimRegistry, UserFaucetState, WalletCluster},
+};
+
+#[derive(Accounts)]
+pub struct CheckEligibility<'info> {
+    ///CHECK: the wallet being checked, only used to derive PDAs
+    pub claimer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [b"user_registry", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer registry, may not exist
+    pub user_claim_registry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"user_faucet", claimer.key().as_ref(), faucet_registry.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer state at this faucet, may not exist
+    pub user_faucet_state: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"block", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: protocol blocklist entry, may not exist
+    pub protocol_block_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"list", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: faucet block/allowlist entry, may not exist
+    pub faucet_list_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"bond", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer bond, may not exist
+    pub claimer_bond: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"cluster_member", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: cluster membership, may not exist
+    pub cluster_membership: UncheckedAccount<'info>,
+
+    /// The claimer's cluster, required when they have a cluster membership.
+    pub wallet_cluster: Option<Account<'info, WalletCluster>>,
+}
+
+/// Answer of `check_eligibility`, returned as the instruction's return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Eligibility {
+    /// Whether a claim of the requested amount would pass right now.
+    pub eligible: bool,
+    /// Error code of the first check that would reject the claim.
+    pub error_code: Option<u32>,
+    /// Seconds until every cooldown and the claim-count quota allow the next claim.
+    pub seconds_remaining: i64,
+    /// Claims left in the faucet's quota window, `None` without a quota.
+    pub remaining_claims: Option<u8>,
+    /// Amount left in the faucet's quota window, `None` without an amount cap.
+    pub remaining_amount: Option<u64>,
+    /// Amount left in the faucet's budget for the current period, `None` without a budget.
+    pub remaining_budget: Option<u64>,
+    pub reputation: u16,
+}
+
+impl<'info> CheckEligibility<'info> {
+    /// Runs the claim checks for `amount` without writing anything. Attestations and claimer
+    /// signatures are per transaction and not part of the answer.
+    pub fn check_eligibility(&self, amount: u64) -> Result<Eligibility> {
+        let clock = Clock::get()?;
+        let curr_time = clock.unix_timestamp;
+
+        let user_claim_registry = load_or_empty::<UserClaimRegistry>(&self.user_claim_registry)?;
+        let user_faucet_state = load_or_empty::<UserFaucetState>(&self.user_faucet_state)?;
+
+        // Validated before `ready_at` uses it: a cluster that isn't the claimer's only shows
+        // up as the `ClusterMismatch` verdict below.
+        let wallet_cluster =
+            verified_cluster(&self.cluster_membership, self.wallet_cluster.as_ref())
+                .ok()
+                .flatten();
+
+        // Budget and period rollover are checked on a copy, the registry stays untouched.
+        let mut faucet_registry = (*self.faucet_registry).clone();
+        consume_budget(&mut faucet_registry, 0, &clock)?;
+
+        let cooldown_seconds = faucet_registry.cooldown_seconds;
+        let mut ready_at = curr_time;
+
+        if user_claim_registry.user != Pubkey::default() {
+            let global_cooldown = faucet_registry.global_cooldown(&self.protocol_config);
+            ready_at = ready_at.max(
+                user_claim_registry
+                    .last_claim_timestamp
+                    .saturating_add(global_cooldown),
+            );
+        }
+
+        if user_faucet_state.user != Pubkey::default() {
+            ready_at = ready_at.max(
+                user_faucet_state
+                    .last_claim_timestamp
+                    .saturating_add(cooldown_seconds),
+            );
+        }
+
+        if let Some(last_claim_timestamp) =
+            wallet_cluster.and_then(|wallet_cluster| wallet_cluster.last_claim_timestamp)
+        {
+            ready_at = ready_at.max(last_claim_timestamp.saturating_add(cooldown_seconds));
+        }
+
+        let (mut remaining_claims, mut remaining_amount) = (None, None);
+
+        if let Some(quota) = faucet_registry.claim_quota {
+            let (claims_in_window, amount_in_window) =
+                user_faucet_state.window_usage(curr_time, quota.window_seconds);
+
+            remaining_claims = Some(quota.max_claims.saturating_sub(claims_in_window));
+            remaining_amount =
+                (quota.max_amount > 0).then(|| quota.max_amount.saturating_sub(amount_in_window));
+
+            if let Some(frees_at) =
+                user_faucet_state.window_frees_at(curr_time, quota.window_seconds, quota.max_claims)
+            {
+                ready_at = ready_at.max(frees_at);
+            }
+        }
+
+        let remaining_budget = faucet_registry.claim_budget.map(|budget| {
+            budget
+                .max_amount
+                .saturating_sub(faucet_registry.budget_spent)
+        });
+
+        let verdict = check_wallet_lists(
+            &faucet_registry,
+            &self.protocol_block_entry,
+            &self.faucet_list_entry,
+            curr_time,
+        )
+        .and_then(|_| check_bond(&faucet_registry, &self.claimer_bond, true))
+        .and_then(|_| {
+            check_cluster(
+                &faucet_registry,
+                &self.cluster_membership,
+                self.wallet_cluster.as_ref(),
+                curr_time,
+            )
+        })
+        .and_then(|_| {
+            check_claim(
+                &faucet_registry,
+                &self.protocol_config,
+                &user_claim_registry,
+                &user_faucet_state,
+                amount,
+                curr_time,
+            )
+        })
+        .and_then(|_| consume_budget(&mut faucet_registry, amount, &clock));
+
+        let error_code = verdict.err().as_ref().map(error_code);
+
+        msg!(
+            "ELIGIBILITY OF {}: {:?}, {} SECONDS REMAINING",
+            self.claimer.key(),
+            error_code,
+            ready_at - curr_time
+        );
+
+        Ok(Eligibility {
+            eligible: error_code.is_none(),
+            error_code,
+            seconds_remaining: ready_at - curr_time,
+            remaining_claims,
+            remaining_amount,
+            remaining_budget,
+            reputation: user_claim_registry.reputation(),
+        })
+    }
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    errors::TapShieldErr,
+    guard::{run_claim, ClaimAccounts},
+    states::{
+        ClaimLog, ClaimRecord, FaucetRegistry, ProtocolConfig, UserFaucetState, WalletCluster,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimTokens<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub claimer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.is_recorder(&operator.key()) @ TapShieldErr::UnauthorizedFaucet,
+        constraint = faucet_registry.mint == Some(mint.key()) @ TapShieldErr::FaucetMintMismatch,
+        constraint = !faucet_registry.require_claimer_signature || claimer.is_signer @ TapShieldErr::ClaimerSignatureRequired
+    )]
+    pub faucet_registry: Box<Account<'info, FaucetRegistry>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [b"vault", faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = mint,
+        associated_token::authority = claimer,
+        associated_token::token_program = token_program
+    )]
+    pub claimer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = ClaimRecord::DISCRIMINATOR.len() + ClaimRecord::INIT_SPACE,
+        seeds = [b"claim", claimer.key().as_ref(), faucet_registry.key().as_ref(), &faucet_registry.total_claims.to_le_bytes()],
+        bump
+    )]
+    pub claim_record: Option<Box<Account<'info, ClaimRecord>>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_registry", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer registry, may not exist; only written for signed claims or at registry-approved faucets
+    pub user_claim_registry: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserFaucetState::DISCRIMINATOR.len() + UserFaucetState::INIT_SPACE,
+        seeds = [b"user_faucet", claimer.key().as_ref(), faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub user_faucet_state: Box<Account<'info, UserFaucetState>>,
+
+    #[account(
+        seeds = [b"block", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: protocol blocklist entry, may not exist
+    pub protocol_block_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"list", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: faucet block/allowlist entry, may not exist
+    pub faucet_list_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"bond", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer bond, may not exist
+    pub claimer_bond: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"cluster_member", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: cluster membership, may not exist
+    pub cluster_membership: UncheckedAccount<'info>,
+
+    /// The claimer's cluster, required when they have a cluster membership.
+    #[account(mut)]
+    pub wallet_cluster: Option<Box<Account<'info, WalletCluster>>>,
+
+    #[account(
+        address = solana_program::sysvar::instructions::ID
+    )]
+    ///CHECK: only required when the faucet has attesters
+    pub instruction_sysvar: Option<UncheckedAccount<'info>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// The faucet's claim log, required when it has one; the claim record is left out then.
+    #[account(mut)]
+    pub claim_log: Option<AccountLoader<'info, ClaimLog>>,
+}
+
+impl<'info> ClaimTokens<'info> {
+    pub fn claim_tokens(&mut self, amount: u64, bumps: &ClaimTokensBumps) -> Result<()> {
+        require!(
+            self.vault_ata.amount >= amount,
+            TapShieldErr::InsufficientVaultBalance
+        );
+
+        run_claim(
+            ClaimAccounts {
+                payer: &self.operator,
+                claimer: &self.claimer,
+                faucet_registry: &mut self.faucet_registry,
+                protocol_config: &self.protocol_config,
+                claim_record: self.claim_record.as_deref_mut().map(|claim| &mut **claim),
+                user_claim_registry: &self.user_claim_registry,
+                user_claim_registry_bump: bumps.user_claim_registry,
+                user_faucet_state: &mut self.user_faucet_state,
+                user_faucet_state_bump: bumps.user_faucet_state,
+                protocol_block_entry: &self.protocol_block_entry,
+                faucet_list_entry: &self.faucet_list_entry,
+                claimer_bond: &self.claimer_bond,
+                cluster_membership: &self.cluster_membership,
+                wallet_cluster: self.wallet_cluster.as_deref_mut(),
+                instruction_sysvar: self.instruction_sysvar.as_deref(),
+                system_program: &self.system_program,
+                claim_log: self.claim_log.as_ref(),
+            },
+            amount,
+        )?;
+
+        let faucet_key = self.faucet_registry.key();
+
+        let transfer_accounts = TransferChecked {
+            from: self.vault_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.claimer_ata.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"vault", faucet_key.as_ref(), &[bumps.vault]]];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{ClaimLog, FaucetRegistry},
+};
+
+#[derive(Accounts)]
+pub struct CloseClaimLog<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = faucet_registry.claim_log == Some(claim_log.key()) @ TapShieldErr::ClaimLogMismatch
+    )]
+    pub claim_log: AccountLoader<'info, ClaimLog>,
+}
+
+impl<'info> CloseClaimLog<'info> {
+    /// Returns the log's rent to the owner; later claims create `ClaimRecord`s again.
+    pub fn close_claim_log(&mut self) -> Result<()> {
+        let faucet_registry = &mut self.faucet_registry;
+
+        faucet_registry.claim_log = None;
+
+        msg!("FAUCET: {} CLAIM LOG CLOSED", faucet_registry.name);
+
+        Ok(())
+    }
+}
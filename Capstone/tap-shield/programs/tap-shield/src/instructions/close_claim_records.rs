@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    guard::load_optional,
+    states::{ClaimRecord, FaucetRegistry, ProtocolConfig, UserClaimRegistry},
+};
+
+/// Claim records of `claimer` at this faucet are passed (writable) in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CloseClaimRecords<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    ///CHECK: only used to derive the user registry
+    pub claimer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user_registry", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer registry, may not exist when none of their claims reached it
+    pub user_claim_registry: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseClaimRecords<'info> {
+    pub fn close_claim_records(
+        &mut self,
+        claim_records: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(!claim_records.is_empty(), TapShieldErr::InvalidInput);
+
+        let faucet_key = self.faucet_registry.key();
+        let claimer_key = self.claimer.key();
+        let history_window = self.faucet_registry.history_window(&self.protocol_config);
+        let curr_time = Clock::get()?.unix_timestamp;
+
+        let mut closed_claims: u64 = 0;
+        let mut closed_amount: u64 = 0;
+
+        for info in claim_records {
+            let record = Account::<ClaimRecord>::try_from(info)?;
+
+            require_keys_eq!(
+                record.faucet_id,
+                faucet_key,
+                TapShieldErr::InvalidClaimRecord
+            );
+            require_keys_eq!(
+                record.claimer,
+                claimer_key,
+                TapShieldErr::InvalidClaimRecord
+            );
+            require!(
+                curr_time.saturating_sub(record.timestamp) >= history_window,
+                TapShieldErr::ClaimRecordStillActive
+            );
+
+            closed_claims += 1;
+            closed_amount = closed_amount.saturating_add(record.amount);
+
+            record.close(self.owner.to_account_info())?;
+        }
+
+        let faucet_registry = &mut self.faucet_registry;
+        faucet_registry.closed_claims += closed_claims;
+        faucet_registry.open_claim_records = faucet_registry
+            .open_claim_records
+            .saturating_sub(closed_claims);
+        faucet_registry.closed_amount = faucet_registry.closed_amount.saturating_add(closed_amount);
+
+        if let Some(mut user_claim_registry) =
+            load_optional::<UserClaimRegistry>(&self.user_claim_registry)?
+        {
+            user_claim_registry.closed_claims += closed_claims;
+            user_claim_registry.closed_amount = user_claim_registry
+                .closed_amount
+                .saturating_add(closed_amount);
+
+            let mut data = self.user_claim_registry.try_borrow_mut_data()?;
+            user_claim_registry.try_serialize(&mut &mut data[..])?;
+        }
+
+        msg!(
+            "FAUCET: {} CLOSED {} CLAIM RECORDS OF {}",
+            faucet_registry.name,
+            closed_claims,
+            claimer_key
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{close_account, CloseAccount, TokenAccount, TokenInterface};
+
+use crate::{errors::TapShieldErr, states::FaucetRegistry};
+
+/// Token faucets also pass their (empty) vault ATA and token program; the ATA is closed
+/// along with the registry.
+#[derive(Accounts)]
+pub struct CloseFaucet<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        seeds = [b"vault", faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub vault_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> CloseFaucet<'info> {
+    pub fn close_faucet(&mut self, bumps: &CloseFaucetBumps) -> Result<()> {
+        require!(
+            self.vault.lamports() == 0,
+            TapShieldErr::FaucetVaultNotEmpty
+        );
+        // Slash reports are resolved against this registry, so it must outlive them.
+        require!(
+            self.faucet_registry.pending_slash_reports == 0,
+            TapShieldErr::FaucetHasPendingReports
+        );
+        // A re-registered faucet starts its claim index at 0 again; old records would collide.
+        require!(
+            self.faucet_registry.open_claim_records == 0,
+            TapShieldErr::FaucetHasOpenClaimRecords
+        );
+        // The claim log holds rent of its own; `close_claim_log` returns it first.
+        require!(
+            self.faucet_registry.claim_log.is_none(),
+            TapShieldErr::ClaimLogMismatch
+        );
+
+        if let Some(mint) = self.faucet_registry.mint {
+            let (Some(vault_ata), Some(token_program)) = (&self.vault_ata, &self.token_program)
+            else {
+                return err!(TapShieldErr::FaucetMintMismatch);
+            };
+
+            require_keys_eq!(
+                vault_ata.key(),
+                get_associated_token_address_with_program_id(
+                    &self.vault.key(),
+                    &mint,
+                    &token_program.key()
+                ),
+                TapShieldErr::FaucetMintMismatch
+            );
+            require!(vault_ata.amount == 0, TapShieldErr::FaucetVaultNotEmpty);
+
+            let faucet_key = self.faucet_registry.key();
+            let signer_seeds: &[&[&[u8]]] = &[&[b"vault", faucet_key.as_ref(), &[bumps.vault]]];
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault_ata.to_account_info(),
+                    destination: self.owner.to_account_info(),
+                    authority: self.vault.to_account_info(),
+                },
+                signer_seeds,
+            );
+
+            close_account(cpi_ctx)?;
+        }
+
+        msg!("FAUCET: {} CLOSED", self.faucet_registry.name);
+
+        Ok(())
+    }
+}
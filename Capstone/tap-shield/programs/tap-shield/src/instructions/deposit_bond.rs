@@ -0,0 +1,61 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{errors::TapShieldErr, states::ClaimerBond};
+
+#[derive(Accounts)]
+pub struct DepositBond<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = ClaimerBond::DISCRIMINATOR.len() + ClaimerBond::INIT_SPACE,
+        seeds = [b"bond", claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_bond: Account<'info, ClaimerBond>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositBond<'info> {
+    pub fn deposit_bond(&mut self, amount: u64, bumps: &DepositBondBumps) -> Result<()> {
+        require!(amount > 0, TapShieldErr::InvalidInput);
+
+        let cpi_accounts = Transfer {
+            from: self.claimer.to_account_info(),
+            to: self.claimer_bond.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, amount)?;
+
+        let claimer_bond = &mut self.claimer_bond;
+
+        if claimer_bond.claimer == Pubkey::default() {
+            claimer_bond.version = ClaimerBond::VERSION;
+            claimer_bond.claimer = self.claimer.key();
+            claimer_bond.bump = bumps.claimer_bond;
+        }
+
+        claimer_bond.amount = claimer_bond
+            .amount
+            .checked_add(amount)
+            .ok_or(TapShieldErr::InvalidInput)?;
+        claimer_bond.deposited_at = Clock::get()?.unix_timestamp;
+
+        msg!(
+            "BONDED {} LAMPORTS FOR {}. TOTAL BOND: {}",
+            amount,
+            claimer_bond.claimer,
+            claimer_bond.amount
+        );
+
+        Ok(())
+    }
+}
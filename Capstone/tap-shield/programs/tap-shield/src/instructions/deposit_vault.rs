@@ -0,0 +1,51 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{errors::TapShieldErr, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct DepositVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositVault<'info> {
+    pub fn deposit_vault(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, TapShieldErr::InvalidInput);
+
+        let cpi_accounts = Transfer {
+            from: self.owner.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, amount)?;
+
+        msg!(
+            "DEPOSITED {} LAMPORTS INTO VAULT OF {}",
+            amount,
+            self.faucet_registry.name
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::ReputationChanged,
+    states::{
+        FaucetRegistry, ReputationFlag, UserClaimRegistry, UserFaucetState, MAX_FLAGS_PER_FAUCET,
+    },
+};
+
+#[derive(Accounts)]
+pub struct FlagClaimer<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    ///CHECK: the flagged claimer, only used to derive PDAs
+    pub claimer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.is_recorder(&operator.key()) @ TapShieldErr::UnauthorizedFaucet,
+        constraint = faucet_registry.reputation_approved @ TapShieldErr::ReputationNotApproved
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    /// Only wallets that claimed at this faucet can be flagged by it.
+    #[account(
+        mut,
+        seeds = [b"user_faucet", claimer.key().as_ref(), faucet_registry.key().as_ref()],
+        bump = user_faucet_state.bump
+    )]
+    pub user_faucet_state: Account<'info, UserFaucetState>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserClaimRegistry::DISCRIMINATOR.len() + UserClaimRegistry::INIT_SPACE,
+        seeds = [b"user_registry", claimer.key().as_ref()],
+        bump
+    )]
+    pub user_claim_registry: Account<'info, UserClaimRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FlagClaimer<'info> {
+    /// Lowers the claimer's reputation. Rejected claims revert along with any state they
+    /// touch, so operators report them here after the fact. Each faucet can flag a claimer
+    /// at most `MAX_FLAGS_PER_FAUCET` times.
+    pub fn flag_claimer(&mut self, flag: ReputationFlag, bumps: &FlagClaimerBumps) -> Result<()> {
+        let user_faucet_state = &mut self.user_faucet_state;
+
+        require!(
+            user_faucet_state.flag_count < MAX_FLAGS_PER_FAUCET,
+            TapShieldErr::FlagLimitReached
+        );
+
+        user_faucet_state.flag_count += 1;
+
+        let user_claim_registry = &mut self.user_claim_registry;
+
+        if user_claim_registry.user == Pubkey::default() {
+            user_claim_registry.version = UserClaimRegistry::VERSION;
+            user_claim_registry.user = self.claimer.key();
+            user_claim_registry.bump = bumps.user_claim_registry;
+        }
+
+        let old_score = user_claim_registry.reputation();
+        let new_score = user_claim_registry.adjust_reputation(-(flag.penalty() as i32));
+
+        msg!(
+            "FAUCET: {} FLAGGED {} FOR {:?}. REPUTATION {} -> {}",
+            self.faucet_registry.name,
+            self.claimer.key(),
+            flag,
+            old_score,
+            new_score
+        );
+
+        emit!(ReputationChanged {
+            faucet: self.faucet_registry.key(),
+            claimer: self.claimer.key(),
+            flag,
+            old_score,
+            new_score,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{ClaimLog, FaucetRegistry},
+};
+
+#[derive(Accounts)]
+pub struct InitClaimLog<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet,
+        constraint = faucet_registry.claim_log.is_none() @ TapShieldErr::ClaimLogMismatch
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    /// Allocated beforehand with `ClaimLog::SPACE` bytes and owned by this program.
+    #[account(zero)]
+    pub claim_log: AccountLoader<'info, ClaimLog>,
+}
+
+impl<'info> InitClaimLog<'info> {
+    /// From here on the faucet's claims are appended to the log instead of creating
+    /// `ClaimRecord`s.
+    pub fn init_claim_log(&mut self) -> Result<()> {
+        let mut claim_log = self.claim_log.load_init()?;
+
+        claim_log.faucet = self.faucet_registry.key();
+        claim_log.version = ClaimLog::VERSION;
+
+        let faucet_registry = &mut self.faucet_registry;
+
+        faucet_registry.claim_log = Some(self.claim_log.key());
+
+        msg!(
+            "FAUCET: {} CLAIM LOG SET TO {}",
+            faucet_registry.name,
+            self.claim_log.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{errors::TapShieldErr, states::ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::DISCRIMINATOR.len() + ProtocolConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Only the program's upgrade authority may create the config and become its admin.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeConfig<'info> {
+    pub fn initialize_config(
+        &mut self,
+        global_cooldown_seconds: i64,
+        bumps: &InitializeConfigBumps,
+    ) -> Result<()> {
+        require!(global_cooldown_seconds >= 0, TapShieldErr::InvalidCooldown);
+
+        let protocol_config = &mut self.protocol_config;
+
+        protocol_config.version = ProtocolConfig::VERSION;
+        protocol_config.admin = self.admin.key();
+        protocol_config.global_cooldown_seconds = global_cooldown_seconds;
+        protocol_config.bump = bumps.protocol_config;
+
+        msg!(
+            "PROTOCOL CONFIG INITIALIZED BY {} WITH GLOBAL COOLDOWN OF {} SECONDS",
+            self.admin.key(),
+            global_cooldown_seconds
+        );
+
+        Ok(())
+    }
+}
@@ -1,17 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::TapShieldErr, states::FaucetRegistry};
+use crate::{errors::TapShieldErr, events::FaucetRegistered, states::FaucetRegistry};
 
 #[derive(Accounts)]
+#[instruction(faucet_id: Pubkey)]
 pub struct InitializeFaucet<'info> {
     #[account(mut)]
-    pub operator: Signer<'info>,
+    pub owner: Signer<'info>,
 
     #[account(
         init,
-        payer = operator,
+        payer = owner,
         space = FaucetRegistry::DISCRIMINATOR.len() + FaucetRegistry::INIT_SPACE,
-        seeds = [b"faucet", operator.key().as_ref()],
+        seeds = [b"faucet", faucet_id.as_ref()],
         bump
     )]
     pub faucet_registry: Account<'info, FaucetRegistry>,
@@ -20,24 +21,72 @@ pub struct InitializeFaucet<'info> {
 }
 
 impl<'info> InitializeFaucet<'info> {
-    pub fn initialize_faucet(&mut self, name: String) -> Result<()> {
+    pub fn initialize_faucet(
+        &mut self,
+        faucet_id: Pubkey,
+        name: String,
+        cooldown_seconds: i64,
+        bumps: &InitializeFaucetBumps,
+    ) -> Result<()> {
         require!(name.len() <= 32, TapShieldErr::FaucetNameTooLong);
         require!(!name.is_empty(), TapShieldErr::InvalidInput);
+        require!(cooldown_seconds > 0, TapShieldErr::InvalidCooldown);
 
         let faucet_registry = &mut self.faucet_registry;
         let clock = Clock::get()?;
 
-        faucet_registry.operator = self.operator.key();
+        faucet_registry.version = FaucetRegistry::VERSION;
+        faucet_registry.faucet_id = faucet_id;
+        faucet_registry.owner = self.owner.key();
+        faucet_registry.pending_owner = None;
+        faucet_registry.recorders = Vec::new();
         faucet_registry.name = name.clone();
         faucet_registry.total_claims = 0;
         faucet_registry.created_at = clock.unix_timestamp;
+        faucet_registry.cooldown_seconds = cooldown_seconds;
+        faucet_registry.global_cooldown_override = None;
+        faucet_registry.claim_quota = None;
+        faucet_registry.mint = None;
+        faucet_registry.require_claimer_signature = false;
+        faucet_registry.attesters = Vec::new();
+        faucet_registry.allowlist_only = false;
+        faucet_registry.closed_claims = 0;
+        faucet_registry.closed_amount = 0;
+        faucet_registry.total_amount = 0;
+        faucet_registry.unique_claimers = 0;
+        faucet_registry.claim_budget = None;
+        faucet_registry.budget_period = 0;
+        faucet_registry.budget_spent = 0;
+        faucet_registry.program_binding = None;
+        faucet_registry.paused = false;
+        faucet_registry.uri = String::new();
+        faucet_registry.category = String::new();
+        faucet_registry.contact = String::new();
+        faucet_registry.bump = bumps.faucet_registry;
+        faucet_registry.required_bond = 0;
+        faucet_registry.reputation_policy = None;
+        faucet_registry.claim_log = None;
+        faucet_registry.reputation_approved = false;
+        faucet_registry.pending_slash_reports = 0;
+        faucet_registry.open_claim_records = 0;
+        faucet_registry.registry_approved = false;
+        faucet_registry.reserved = [0; 64];
 
         msg!(
             "FAUCET: {} REGISTERED SUCCESSFULLY BY {} !",
             name,
-            self.operator.key()
+            self.owner.key()
         );
 
+        emit!(FaucetRegistered {
+            faucet: faucet_registry.key(),
+            faucet_id,
+            owner: self.owner.key(),
+            name,
+            cooldown_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+
         Ok(())
     }
 }
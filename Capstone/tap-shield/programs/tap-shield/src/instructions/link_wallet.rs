@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    guard::load_optional,
+    states::{ClusterMembership, FaucetRegistry, UserFaucetState, WalletCluster},
+};
+
+#[derive(Accounts)]
+#[instruction(cluster_id: Pubkey, wallet: Pubkey)]
+pub struct LinkWallet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.is_cluster_linker(&authority.key()) @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WalletCluster::DISCRIMINATOR.len() + WalletCluster::INIT_SPACE,
+        seeds = [b"cluster", faucet_registry.key().as_ref(), cluster_id.as_ref()],
+        bump
+    )]
+    pub wallet_cluster: Account<'info, WalletCluster>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ClusterMembership::DISCRIMINATOR.len() + ClusterMembership::INIT_SPACE,
+        seeds = [b"cluster_member", faucet_registry.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub cluster_membership: Account<'info, ClusterMembership>,
+
+    #[account(
+        seeds = [b"user_faucet", wallet.as_ref(), faucet_registry.key().as_ref()],
+        bump
+    )]
+    ///CHECK: the wallet's state at this faucet, may not exist
+    pub user_faucet_state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LinkWallet<'info> {
+    /// Adds `wallet` to the cluster `cluster_id` (created on first use, e.g. keyed by the
+    /// funding source). The cluster picks up the wallet's last claim here, so linking a
+    /// wallet that just claimed puts the whole cluster on cooldown.
+    pub fn link_wallet(
+        &mut self,
+        cluster_id: Pubkey,
+        wallet: Pubkey,
+        bumps: &LinkWalletBumps,
+    ) -> Result<()> {
+        let curr_time = Clock::get()?.unix_timestamp;
+        let faucet_key = self.faucet_registry.key();
+        let wallet_cluster = &mut self.wallet_cluster;
+
+        if wallet_cluster.faucet == Pubkey::default() {
+            wallet_cluster.version = WalletCluster::VERSION;
+            wallet_cluster.faucet = faucet_key;
+            wallet_cluster.cluster_id = cluster_id;
+            wallet_cluster.created_by = self.authority.key();
+            wallet_cluster.created_at = curr_time;
+            wallet_cluster.bump = bumps.wallet_cluster;
+        }
+
+        if let Some(state) = load_optional::<UserFaucetState>(&self.user_faucet_state)? {
+            wallet_cluster.last_claim_timestamp = wallet_cluster
+                .last_claim_timestamp
+                .max(Some(state.last_claim_timestamp));
+        }
+
+        wallet_cluster.member_count += 1;
+
+        self.cluster_membership.set_inner(ClusterMembership {
+            version: ClusterMembership::VERSION,
+            faucet: faucet_key,
+            wallet,
+            cluster: wallet_cluster.key(),
+            linked_by: self.authority.key(),
+            linked_at: curr_time,
+            bump: bumps.cluster_membership,
+            reserved: [0; 16],
+        });
+
+        msg!(
+            "WALLET {} LINKED TO CLUSTER {}. MEMBERS: {}",
+            wallet,
+            cluster_id,
+            wallet_cluster.member_count
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    migration::{read_legacy, write_migrated},
+    states::{ClaimRecord, LegacyClaimRecord},
+};
+
+/// Permissionless: the layout change is deterministic, the payer only covers the extra rent.
+#[derive(Accounts)]
+pub struct MigrateClaimRecord<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    ///CHECK: legacy layout, parsed by `read_legacy`
+    pub claim_record: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateClaimRecord<'info> {
+    pub fn migrate_claim_record(&mut self) -> Result<()> {
+        let legacy: LegacyClaimRecord = read_legacy::<ClaimRecord, _>(
+            &self.claim_record,
+            LegacyClaimRecord::INIT_SPACE,
+            ClaimRecord::VERSION,
+        )?;
+
+        let claim_record = ClaimRecord {
+            version: ClaimRecord::VERSION,
+            claimer: legacy.claimer,
+            faucet_id: legacy.faucet_id,
+            amount: legacy.amount,
+            timestamp: legacy.timestamp,
+            reserved: [0; 16],
+        };
+
+        write_migrated(
+            &claim_record,
+            ClaimRecord::DISCRIMINATOR.len() + ClaimRecord::INIT_SPACE,
+            &self.claim_record,
+            &self.payer,
+            &self.system_program,
+        )?;
+
+        msg!(
+            "CLAIM RECORD {} MIGRATED TO VERSION {}",
+            self.claim_record.key(),
+            ClaimRecord::VERSION
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    migration::{read_legacy, write_migrated},
+    states::{FaucetRegistry, LegacyFaucetRegistry},
+};
+
+/// Upgrades a pre-versioning registry in place. Legacy registries live at
+/// `["faucet", operator]`, so the operator key becomes both the faucet id and the owner.
+#[derive(Accounts)]
+pub struct MigrateFaucetRegistry<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    ///CHECK: legacy layout, parsed by `read_legacy`
+    pub faucet_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateFaucetRegistry<'info> {
+    /// Legacy registries stored no cooldown, so the owner supplies it.
+    pub fn migrate_faucet_registry(&mut self, cooldown_seconds: i64) -> Result<()> {
+        require!(cooldown_seconds > 0, TapShieldErr::InvalidCooldown);
+
+        let legacy: LegacyFaucetRegistry = read_legacy::<FaucetRegistry, _>(
+            &self.faucet_registry,
+            LegacyFaucetRegistry::INIT_SPACE,
+            FaucetRegistry::VERSION,
+        )?;
+
+        require_keys_eq!(
+            legacy.operator,
+            self.owner.key(),
+            TapShieldErr::UnauthorizedFaucet
+        );
+
+        let (address, bump) =
+            Pubkey::find_program_address(&[b"faucet", legacy.operator.as_ref()], &crate::ID);
+
+        require_keys_eq!(
+            address,
+            self.faucet_registry.key(),
+            ErrorCode::ConstraintSeeds
+        );
+
+        let faucet_registry = FaucetRegistry {
+            version: FaucetRegistry::VERSION,
+            faucet_id: legacy.operator,
+            owner: legacy.operator,
+            pending_owner: None,
+            recorders: Vec::new(),
+            name: legacy.name,
+            total_claims: legacy.total_claims,
+            created_at: legacy.created_at,
+            cooldown_seconds,
+            global_cooldown_override: None,
+            claim_quota: None,
+            mint: None,
+            require_claimer_signature: false,
+            attesters: Vec::new(),
+            allowlist_only: false,
+            closed_claims: 0,
+            closed_amount: 0,
+            total_amount: 0,
+            unique_claimers: 0,
+            claim_budget: None,
+            budget_period: 0,
+            budget_spent: 0,
+            program_binding: None,
+            paused: false,
+            uri: String::new(),
+            category: String::new(),
+            contact: String::new(),
+            bump,
+            required_bond: 0,
+            reputation_policy: None,
+            claim_log: None,
+            reputation_approved: false,
+            pending_slash_reports: 0,
+            open_claim_records: legacy.total_claims,
+            registry_approved: false,
+            reserved: [0; 64],
+        };
+
+        write_migrated(
+            &faucet_registry,
+            FaucetRegistry::DISCRIMINATOR.len() + FaucetRegistry::INIT_SPACE,
+            &self.faucet_registry,
+            &self.owner,
+            &self.system_program,
+        )?;
+
+        msg!(
+            "FAUCET: {} MIGRATED TO VERSION {}",
+            faucet_registry.name,
+            FaucetRegistry::VERSION
+        );
+
+        Ok(())
+    }
+}
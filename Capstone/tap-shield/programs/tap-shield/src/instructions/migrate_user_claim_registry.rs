@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    migration::{read_legacy, write_migrated},
+    states::{LegacyUserClaimRegistry, UserClaimRegistry},
+};
+
+/// Permissionless: the layout change is deterministic, the payer only covers the extra rent.
+#[derive(Accounts)]
+pub struct MigrateUserClaimRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    ///CHECK: legacy layout, parsed by `read_legacy`
+    pub user_claim_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateUserClaimRegistry<'info> {
+    pub fn migrate_user_claim_registry(&mut self) -> Result<()> {
+        let legacy: LegacyUserClaimRegistry = read_legacy::<UserClaimRegistry, _>(
+            &self.user_claim_registry,
+            LegacyUserClaimRegistry::INIT_SPACE,
+            UserClaimRegistry::VERSION,
+        )?;
+
+        let (address, bump) =
+            Pubkey::find_program_address(&[b"user_registry", legacy.user.as_ref()], &crate::ID);
+
+        require_keys_eq!(
+            address,
+            self.user_claim_registry.key(),
+            ErrorCode::ConstraintSeeds
+        );
+
+        let user_claim_registry = UserClaimRegistry {
+            version: UserClaimRegistry::VERSION,
+            user: legacy.user,
+            last_claim_timestamp: legacy.last_claim_timestamp,
+            last_faucet: legacy.last_faucet,
+            total_claims_across_faucets: legacy.total_claims_across_faucets,
+            bump,
+            closed_claims: 0,
+            closed_amount: 0,
+            reputation_score: None,
+            reserved: [0; 61],
+        };
+
+        write_migrated(
+            &user_claim_registry,
+            UserClaimRegistry::DISCRIMINATOR.len() + UserClaimRegistry::INIT_SPACE,
+            &self.user_claim_registry,
+            &self.payer,
+            &self.system_program,
+        )?;
+
+        msg!(
+            "USER REGISTRY OF {} MIGRATED TO VERSION {}",
+            legacy.user,
+            UserClaimRegistry::VERSION
+        );
+
+        Ok(())
+    }
+}
@@ -2,4 +2,148 @@ pub mod initialize_faucet;
 pub use initialize_faucet::*;
 
 pub mod record_claim;
-pub use record_claim::*;
\ No newline at end of file
+pub use record_claim::*;
+
+pub mod update_faucet_policy;
+pub use update_faucet_policy::*;
+
+pub mod initialize_config;
+pub use initialize_config::*;
+
+pub mod update_config;
+pub use update_config::*;
+
+pub mod set_faucet_override;
+pub use set_faucet_override::*;
+
+pub mod deposit_vault;
+pub use deposit_vault::*;
+
+pub mod withdraw_vault;
+pub use withdraw_vault::*;
+
+pub mod claim;
+pub use claim::*;
+
+pub mod bind_faucet_mint;
+pub use bind_faucet_mint::*;
+
+pub mod deposit_tokens;
+pub use deposit_tokens::*;
+
+pub mod withdraw_tokens;
+pub use withdraw_tokens::*;
+
+pub mod claim_tokens;
+pub use claim_tokens::*;
+
+pub mod set_attesters;
+pub use set_attesters::*;
+
+pub mod add_recorder;
+pub use add_recorder::*;
+
+pub mod remove_recorder;
+pub use remove_recorder::*;
+
+pub mod rotate_recorder;
+pub use rotate_recorder::*;
+
+pub mod transfer_faucet_ownership;
+pub use transfer_faucet_ownership::*;
+
+pub mod accept_faucet_ownership;
+pub use accept_faucet_ownership::*;
+
+pub mod add_protocol_block;
+pub use add_protocol_block::*;
+
+pub mod remove_protocol_block;
+pub use remove_protocol_block::*;
+
+pub mod set_faucet_list_entry;
+pub use set_faucet_list_entry::*;
+
+pub mod remove_faucet_list_entry;
+pub use remove_faucet_list_entry::*;
+
+pub mod set_allowlist_only;
+pub use set_allowlist_only::*;
+
+pub mod close_claim_records;
+pub use close_claim_records::*;
+
+pub mod set_claim_budget;
+pub use set_claim_budget::*;
+
+pub mod check_and_record;
+pub use check_and_record::*;
+
+pub mod bind_faucet_program;
+pub use bind_faucet_program::*;
+
+pub mod pause_faucet;
+pub use pause_faucet::*;
+
+pub mod resume_faucet;
+pub use resume_faucet::*;
+
+pub mod close_faucet;
+pub use close_faucet::*;
+
+pub mod update_faucet_metadata;
+pub use update_faucet_metadata::*;
+
+pub mod migrate_faucet_registry;
+pub use migrate_faucet_registry::*;
+
+pub mod migrate_user_claim_registry;
+pub use migrate_user_claim_registry::*;
+
+pub mod migrate_claim_record;
+pub use migrate_claim_record::*;
+
+pub mod set_required_bond;
+pub use set_required_bond::*;
+
+pub mod deposit_bond;
+pub use deposit_bond::*;
+
+pub mod withdraw_bond;
+pub use withdraw_bond::*;
+
+pub mod report_abuse;
+pub use report_abuse::*;
+
+pub mod resolve_slash_report;
+pub use resolve_slash_report::*;
+
+pub mod link_wallet;
+pub use link_wallet::*;
+
+pub mod unlink_wallet;
+pub use unlink_wallet::*;
+
+pub mod set_reputation_policy;
+pub use set_reputation_policy::*;
+
+pub mod flag_claimer;
+pub use flag_claimer::*;
+
+pub mod check_eligibility;
+pub use check_eligibility::*;
+
+pub mod record_claims_batch;
+pub use record_claims_batch::*;
+
+pub mod init_claim_log;
+pub use init_claim_log::*;
+
+pub mod close_claim_log;
+pub use close_claim_log::*;
+
+pub mod set_reputation_approval;
+pub use set_reputation_approval::*;
+
+pub mod set_registry_approval;
+pub use set_registry_approval::*;
\ No newline at end of file
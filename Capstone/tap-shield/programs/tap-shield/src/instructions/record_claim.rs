@@ -2,27 +2,36 @@ use anchor_lang::prelude::*;
 
 use crate::{
     errors::TapShieldErr,
-    states::{ClaimRecord, FaucetRegistry, UserClaimRegistry},
+    guard::{run_claim, ClaimAccounts},
+    states::{
+        ClaimLog, ClaimRecord, FaucetRegistry, ProtocolConfig, UserFaucetState, WalletCluster,
+    },
 };
 
 #[derive(Accounts)]
 #[instruction(claimer_pubkey: Pubkey)]
-
 pub struct RecordClaim<'info> {
     #[account(mut)]
     pub operator: Signer<'info>,
 
-    ///CHECK: this is safe
+    ///CHECK: signs when the claimer co-signs the claim; the global user registry is only updated then
     pub claimer: UncheckedAccount<'info>,
 
     #[account(
         mut,
-        seeds = [b"faucet", operator.key().as_ref()],
-        bump,
-        constraint = faucet_registry.operator == operator.key() @ TapShieldErr::UnauthorizedFaucet
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.is_recorder(&operator.key()) @ TapShieldErr::UnauthorizedFaucet,
+        constraint = !faucet_registry.require_claimer_signature || claimer.is_signer @ TapShieldErr::ClaimerSignatureRequired
     )]
     pub faucet_registry: Account<'info, FaucetRegistry>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     #[account(
         init,
         payer = operator,
@@ -30,16 +39,24 @@ pub struct RecordClaim<'info> {
         seeds = [b"claim", claimer.key().as_ref(), faucet_registry.key().as_ref(), &faucet_registry.total_claims.to_le_bytes()],
         bump
     )]
-    pub claim_record: Account<'info, ClaimRecord>,
+    pub claim_record: Option<Account<'info, ClaimRecord>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_registry", claimer_pubkey.as_ref()],
+        bump
+    )]
+    ///CHECK: claimer registry, may not exist; only written for signed claims or at registry-approved faucets
+    pub user_claim_registry: UncheckedAccount<'info>,
 
     #[account(
         init_if_needed,
         payer = operator,
-        space = UserClaimRegistry::DISCRIMINATOR.len() + UserClaimRegistry::INIT_SPACE,
-        seeds = [b"user_registry", claimer_pubkey.as_ref()],
+        space = UserFaucetState::DISCRIMINATOR.len() + UserFaucetState::INIT_SPACE,
+        seeds = [b"user_faucet", claimer.key().as_ref(), faucet_registry.key().as_ref()],
         bump
     )]
-    pub user_claim_registry: Account<'info, UserClaimRegistry>,
+    pub user_faucet_state: Account<'info, UserFaucetState>,
 
     // #[account(
     //     mut,
@@ -47,8 +64,49 @@ pub struct RecordClaim<'info> {
     //     bump
     // )]
     // pub last_claim_record: Option<Account<'info, ClaimRecord>>,
+    #[account(
+        seeds = [b"block", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: protocol blocklist entry, may not exist
+    pub protocol_block_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"list", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: faucet block/allowlist entry, may not exist
+    pub faucet_list_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"bond", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer bond, may not exist
+    pub claimer_bond: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"cluster_member", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: cluster membership, may not exist
+    pub cluster_membership: UncheckedAccount<'info>,
+
+    /// The claimer's cluster, required when they have a cluster membership.
+    #[account(mut)]
+    pub wallet_cluster: Option<Account<'info, WalletCluster>>,
+
+    #[account(
+        address = solana_program::sysvar::instructions::ID
+    )]
+    ///CHECK: only required when the faucet has attesters
+    pub instruction_sysvar: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
+
+    /// The faucet's claim log, required when it has one; the claim record is left out then.
+    #[account(mut)]
+    pub claim_log: Option<AccountLoader<'info, ClaimLog>>,
 }
 
 impl<'info> RecordClaim<'info> {
@@ -56,60 +114,34 @@ impl<'info> RecordClaim<'info> {
         &mut self,
         claimer_pubkey: Pubkey,
         amount: u64,
-        cooldown_second: i64,
+        bumps: &RecordClaimBumps,
     ) -> Result<()> {
-        let claim = &mut self.claim_record;
-        let faucet_registry = &mut self.faucet_registry;
-        let user_claim_registry = &mut self.user_claim_registry;
-        let clock = Clock::get()?;
-        let curr_time = clock.unix_timestamp;
-
-        require!(amount > 0, TapShieldErr::InvalidInput);
-        require!(cooldown_second > 0, TapShieldErr::InvalidCooldown);
         require!(
             claimer_pubkey == self.claimer.key(),
             TapShieldErr::InvalidClaimer
         );
 
-        // if let Some(last_claim) = &self.last_claim_record {
-        if user_claim_registry.user != Pubkey::default() {
-            let time_since_last_claim = curr_time
-                .checked_sub(user_claim_registry.last_claim_timestamp)
-                .ok_or(TapShieldErr::InvalidTimestamp)?;
-
-            require!(
-                time_since_last_claim >= cooldown_second,
-                TapShieldErr::ClaimTooRecent
-            );
-
-            msg!(
-                "COOLDOWN HAS BEEN PASSED. {} SECONDS SINCE LAST CLAIM",
-                time_since_last_claim
-            );
-        } else {
-            msg!("FIRST TIME CLAIMER ? ELIGIBLE");
-        }
-
-        claim.claimer = claimer_pubkey;
-        claim.faucet_id = faucet_registry.key();
-        claim.amount = amount;
-        claim.timestamp = curr_time;
-
-        user_claim_registry.user = claimer_pubkey;
-        user_claim_registry.last_claim_timestamp = curr_time;
-        user_claim_registry.last_faucet = faucet_registry.key();
-
-        user_claim_registry.total_claims_across_faucets += 1;
-
-        faucet_registry.total_claims += 1;
-
-        msg!(
-            "CLAIM RECORD FROM: {}, CLAIMED {} FROM {}",
-            claimer_pubkey,
+        run_claim(
+            ClaimAccounts {
+                payer: &self.operator,
+                claimer: &self.claimer,
+                faucet_registry: &mut self.faucet_registry,
+                protocol_config: &self.protocol_config,
+                claim_record: self.claim_record.as_deref_mut(),
+                user_claim_registry: &self.user_claim_registry,
+                user_claim_registry_bump: bumps.user_claim_registry,
+                user_faucet_state: &mut self.user_faucet_state,
+                user_faucet_state_bump: bumps.user_faucet_state,
+                protocol_block_entry: &self.protocol_block_entry,
+                faucet_list_entry: &self.faucet_list_entry,
+                claimer_bond: &self.claimer_bond,
+                cluster_membership: &self.cluster_membership,
+                wallet_cluster: self.wallet_cluster.as_mut(),
+                instruction_sysvar: self.instruction_sysvar.as_deref(),
+                system_program: &self.system_program,
+                claim_log: self.claim_log.as_ref(),
+            },
             amount,
-            faucet_registry.name
-        );
-
-        Ok(())
+        )
     }
 }
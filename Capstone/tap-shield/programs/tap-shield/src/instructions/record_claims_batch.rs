@@ -0,0 +1,258 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    errors::TapShieldErr,
+    guard::{
+        append_claim_log, check_bond, check_claim, check_cluster, check_wallet_lists,
+        commit_cluster, commit_counters, commit_user_claim_registry, commit_user_faucet_state,
+        consume_budget, load_or_empty, report_rejection, write_pda,
+    },
+    states::{
+        ClaimLog, FaucetRegistry, ProtocolConfig, UserClaimRegistry, UserFaucetState, WalletCluster,
+    },
+};
+
+/// Accounts each batch entry takes from `remaining_accounts`, in this order:
+/// user claim registry, user faucet state, protocol block entry, faucet list entry, claimer
+/// bond, cluster membership and wallet cluster (the program id when the claimer has none).
+/// The user faucet state is created when missing, so it and the wallet cluster are writable.
+/// Batched claims aren't signed by their claimers, so the user claim registry is only
+/// updated (and must be writable) at faucets the admin approved.
+pub const BATCH_ACCOUNTS_PER_CLAIM: usize = 7;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct BatchClaim {
+    pub claimer: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BatchMode {
+    /// The first ineligible entry fails the whole batch.
+    Atomic,
+    /// Ineligible entries are skipped and reported through `ClaimRejected`.
+    SkipIneligible,
+}
+
+/// Batched claims don't create `ClaimRecord`s: the per-claim history is the
+/// `ClaimRecorded` event, plus the claim log for faucets that have one.
+#[derive(Accounts)]
+pub struct RecordClaimsBatch<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.is_recorder(&operator.key()) @ TapShieldErr::UnauthorizedFaucet,
+        constraint = !faucet_registry.require_claimer_signature @ TapShieldErr::ClaimerSignatureRequired,
+        constraint = faucet_registry.attesters.is_empty() @ TapShieldErr::AttestationMissing
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The faucet's claim log, required when it has one.
+    #[account(mut)]
+    pub claim_log: Option<AccountLoader<'info, ClaimLog>>,
+}
+
+impl<'info> RecordClaimsBatch<'info> {
+    pub fn record_claims_batch(
+        &mut self,
+        claims: Vec<BatchClaim>,
+        mode: BatchMode,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(!claims.is_empty(), TapShieldErr::InvalidInput);
+        require_eq!(
+            remaining_accounts.len(),
+            claims.len() * BATCH_ACCOUNTS_PER_CLAIM,
+            TapShieldErr::InvalidBatchAccounts
+        );
+
+        let clock = Clock::get()?;
+        let curr_time = clock.unix_timestamp;
+        let faucet_key = self.faucet_registry.key();
+
+        let mut recorded: u32 = 0;
+
+        for (claim, accounts) in claims
+            .iter()
+            .zip(remaining_accounts.chunks_exact(BATCH_ACCOUNTS_PER_CLAIM))
+        {
+            let user_claim_registry_info = &accounts[0];
+            let user_faucet_state_info = &accounts[1];
+            let protocol_block_entry = &accounts[2];
+            let faucet_list_entry = &accounts[3];
+            let claimer_bond = &accounts[4];
+            let cluster_membership = &accounts[5];
+            let wallet_cluster_info = &accounts[6];
+            let claimer = claim.claimer;
+
+            let user_claim_registry_bump = expect_pda(
+                user_claim_registry_info,
+                &[b"user_registry", claimer.as_ref()],
+            )?;
+            let user_faucet_state_bump = expect_pda(
+                user_faucet_state_info,
+                &[b"user_faucet", claimer.as_ref(), faucet_key.as_ref()],
+            )?;
+            expect_pda(protocol_block_entry, &[b"block", claimer.as_ref()])?;
+            expect_pda(
+                faucet_list_entry,
+                &[b"list", faucet_key.as_ref(), claimer.as_ref()],
+            )?;
+            expect_pda(claimer_bond, &[b"bond", claimer.as_ref()])?;
+            expect_pda(
+                cluster_membership,
+                &[b"cluster_member", faucet_key.as_ref(), claimer.as_ref()],
+            )?;
+
+            let mut user_claim_registry =
+                load_or_empty::<UserClaimRegistry>(user_claim_registry_info)?;
+            let mut user_faucet_state = load_or_empty::<UserFaucetState>(user_faucet_state_info)?;
+            let mut wallet_cluster = match wallet_cluster_info.key() {
+                key if key == crate::ID => None,
+                _ => Some(Account::<WalletCluster>::try_from(wallet_cluster_info)?),
+            };
+
+            let verdict = report_rejection(faucet_key, claimer, claim.amount, curr_time, || {
+                check_wallet_lists(
+                    &self.faucet_registry,
+                    protocol_block_entry,
+                    faucet_list_entry,
+                    curr_time,
+                )?;
+
+                check_bond(&self.faucet_registry, claimer_bond, false)?;
+
+                check_cluster(
+                    &self.faucet_registry,
+                    cluster_membership,
+                    wallet_cluster.as_ref(),
+                    curr_time,
+                )?;
+
+                check_claim(
+                    &self.faucet_registry,
+                    &self.protocol_config,
+                    &user_claim_registry,
+                    &user_faucet_state,
+                    claim.amount,
+                    curr_time,
+                )?;
+
+                consume_budget(&mut self.faucet_registry, claim.amount, &clock)
+            });
+
+            if let Err(error) = verdict {
+                match mode {
+                    BatchMode::Atomic => return Err(error),
+                    BatchMode::SkipIneligible => {
+                        msg!("SKIPPED CLAIM OF {}", claimer);
+                        continue;
+                    }
+                }
+            }
+
+            if self.faucet_registry.registry_approved {
+                commit_user_claim_registry(
+                    &mut user_claim_registry,
+                    user_claim_registry_bump,
+                    &self.faucet_registry,
+                    faucet_key,
+                    claimer,
+                    curr_time,
+                );
+
+                write_pda(
+                    user_claim_registry_info,
+                    &user_claim_registry,
+                    UserClaimRegistry::DISCRIMINATOR.len() + UserClaimRegistry::INIT_SPACE,
+                    &[
+                        b"user_registry",
+                        claimer.as_ref(),
+                        &[user_claim_registry_bump],
+                    ],
+                    &self.operator,
+                    &self.system_program,
+                )?;
+            }
+
+            commit_counters(
+                &mut self.faucet_registry,
+                faucet_key,
+                user_claim_registry.total_claims_across_faucets,
+                claimer,
+                claim.amount,
+                curr_time,
+            );
+
+            append_claim_log(
+                &self.faucet_registry,
+                self.claim_log.as_ref(),
+                claimer,
+                claim.amount,
+                curr_time,
+            )?;
+
+            commit_user_faucet_state(
+                &mut self.faucet_registry,
+                faucet_key,
+                &mut user_faucet_state,
+                claimer,
+                user_faucet_state_bump,
+                claim.amount,
+                curr_time,
+            );
+
+            commit_cluster(wallet_cluster.as_deref_mut(), curr_time);
+
+            write_pda(
+                user_faucet_state_info,
+                &user_faucet_state,
+                UserFaucetState::DISCRIMINATOR.len() + UserFaucetState::INIT_SPACE,
+                &[
+                    b"user_faucet",
+                    claimer.as_ref(),
+                    faucet_key.as_ref(),
+                    &[user_faucet_state_bump],
+                ],
+                &self.operator,
+                &self.system_program,
+            )?;
+
+            if let Some(wallet_cluster) = wallet_cluster {
+                wallet_cluster.exit(&crate::ID)?;
+            }
+
+            recorded += 1;
+        }
+
+        msg!(
+            "FAUCET: {} RECORDED {} OF {} BATCHED CLAIMS",
+            self.faucet_registry.name,
+            recorded,
+            claims.len()
+        );
+
+        Ok(())
+    }
+}
+
+/// Checks that `info` is the PDA for `seeds` and returns its bump.
+fn expect_pda(info: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+
+    require_keys_eq!(info.key(), address, TapShieldErr::InvalidBatchAccounts);
+
+    Ok(bump)
+}
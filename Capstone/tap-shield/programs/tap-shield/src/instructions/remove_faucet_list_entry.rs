@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{FaucetRegistry, WalletListEntry},
+};
+
+#[derive(Accounts)]
+pub struct RemoveFaucetListEntry<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"list", faucet_registry.key().as_ref(), list_entry.wallet.as_ref()],
+        bump = list_entry.bump
+    )]
+    pub list_entry: Account<'info, WalletListEntry>,
+}
+
+impl<'info> RemoveFaucetListEntry<'info> {
+    pub fn remove_faucet_list_entry(&mut self) -> Result<()> {
+        msg!(
+            "FAUCET: {} UNLISTED WALLET {}",
+            self.faucet_registry.name,
+            self.list_entry.wallet
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{ProtocolConfig, WalletListEntry},
+};
+
+#[derive(Accounts)]
+pub struct RemoveProtocolBlock<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump,
+        has_one = admin @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"block", block_entry.wallet.as_ref()],
+        bump = block_entry.bump
+    )]
+    pub block_entry: Account<'info, WalletListEntry>,
+}
+
+impl<'info> RemoveProtocolBlock<'info> {
+    pub fn remove_protocol_block(&mut self) -> Result<()> {
+        msg!("WALLET {} UNBLOCKED NETWORK-WIDE", self.block_entry.wallet);
+
+        Ok(())
+    }
+}
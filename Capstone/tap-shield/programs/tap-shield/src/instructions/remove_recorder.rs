@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct RemoveRecorder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> RemoveRecorder<'info> {
+    pub fn remove_recorder(&mut self, recorder: Pubkey) -> Result<()> {
+        let faucet_registry = &mut self.faucet_registry;
+
+        let index = faucet_registry
+            .recorders
+            .iter()
+            .position(|key| *key == recorder)
+            .ok_or(TapShieldErr::RecorderNotFound)?;
+
+        faucet_registry.recorders.swap_remove(index);
+
+        msg!(
+            "FAUCET: {} REMOVED RECORDER {}",
+            faucet_registry.name,
+            recorder
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{ClaimerBond, FaucetRegistry, SlashReport, UserFaucetState, MAX_REASON_LEN},
+};
+
+#[derive(Accounts)]
+pub struct ReportAbuse<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    ///CHECK: the reported claimer, only used to derive PDAs
+    pub claimer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.is_recorder(&operator.key()) @ TapShieldErr::UnauthorizedFaucet,
+        constraint = faucet_registry.required_bond > 0 @ TapShieldErr::BondNotRequired
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    /// Only wallets that claimed at this faucet can be reported by it.
+    #[account(
+        seeds = [b"user_faucet", claimer.key().as_ref(), faucet_registry.key().as_ref()],
+        bump = user_faucet_state.bump
+    )]
+    pub user_faucet_state: Account<'info, UserFaucetState>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", claimer.key().as_ref()],
+        bump = claimer_bond.bump
+    )]
+    pub claimer_bond: Account<'info, ClaimerBond>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = SlashReport::DISCRIMINATOR.len() + SlashReport::INIT_SPACE,
+        seeds = [b"slash", faucet_registry.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    pub slash_report: Account<'info, SlashReport>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReportAbuse<'info> {
+    /// Files a request to slash up to `amount` lamports of the claimer's bond into the
+    /// faucet vault. The bond cannot be withdrawn until the admin resolves the report.
+    pub fn report_abuse(
+        &mut self,
+        amount: u64,
+        reason: String,
+        bumps: &ReportAbuseBumps,
+    ) -> Result<()> {
+        require!(amount > 0, TapShieldErr::InvalidInput);
+        require!(reason.len() <= MAX_REASON_LEN, TapShieldErr::ReasonTooLong);
+
+        self.slash_report.set_inner(SlashReport {
+            version: SlashReport::VERSION,
+            faucet: self.faucet_registry.key(),
+            claimer: self.claimer.key(),
+            reporter: self.operator.key(),
+            amount,
+            reason,
+            created_at: Clock::get()?.unix_timestamp,
+            bump: bumps.slash_report,
+            reserved: [0; 16],
+        });
+
+        self.claimer_bond.pending_reports += 1;
+        self.faucet_registry.pending_slash_reports += 1;
+
+        msg!(
+            "FAUCET: {} REPORTED {} FOR ABUSE. REQUESTED SLASH: {} LAMPORTS",
+            self.faucet_registry.name,
+            self.claimer.key(),
+            amount
+        );
+
+        Ok(())
+    }
+}
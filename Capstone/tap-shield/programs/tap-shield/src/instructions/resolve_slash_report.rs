@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::BondSlashed,
+    states::{ClaimerBond, FaucetRegistry, ProtocolConfig, SlashReport},
+};
+
+#[derive(Accounts)]
+pub struct ResolveSlashReport<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump,
+        has_one = admin @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    ///CHECK: receives slashes too small to make an empty vault rent-exempt
+    #[account(
+        mut,
+        address = faucet_registry.owner @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [b"slash", faucet_registry.key().as_ref(), slash_report.claimer.as_ref()],
+        bump = slash_report.bump,
+        has_one = reporter
+    )]
+    pub slash_report: Account<'info, SlashReport>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", slash_report.claimer.as_ref()],
+        bump = claimer_bond.bump
+    )]
+    pub claimer_bond: Account<'info, ClaimerBond>,
+
+    ///CHECK: receives the report's rent back
+    #[account(mut)]
+    pub reporter: UncheckedAccount<'info>,
+}
+
+impl<'info> ResolveSlashReport<'info> {
+    /// Approving moves the reported amount (capped at the bond) into the faucet vault, or to
+    /// the faucet owner if it would leave the vault below rent exemption; either way the
+    /// report is closed and stops blocking withdrawals.
+    pub fn resolve_slash_report(&mut self, approve: bool) -> Result<()> {
+        let claimer_bond = &mut self.claimer_bond;
+
+        claimer_bond.pending_reports = claimer_bond.pending_reports.saturating_sub(1);
+        self.faucet_registry.pending_slash_reports =
+            self.faucet_registry.pending_slash_reports.saturating_sub(1);
+
+        if !approve {
+            msg!("SLASH REPORT AGAINST {} REJECTED", claimer_bond.claimer);
+
+            return Ok(());
+        }
+
+        let slashed = self.slash_report.amount.min(claimer_bond.amount);
+
+        let vault_balance = self.vault.lamports().saturating_add(slashed);
+        let destination = if vault_balance >= Rent::get()?.minimum_balance(0) {
+            self.vault.to_account_info()
+        } else {
+            self.faucet_owner.to_account_info()
+        };
+
+        claimer_bond.sub_lamports(slashed)?;
+        destination.add_lamports(slashed)?;
+        claimer_bond.amount -= slashed;
+
+        msg!(
+            "SLASHED {} LAMPORTS FROM BOND OF {} INTO {} OF {}",
+            slashed,
+            claimer_bond.claimer,
+            destination.key,
+            self.faucet_registry.name
+        );
+
+        emit!(BondSlashed {
+            faucet: self.faucet_registry.key(),
+            claimer: claimer_bond.claimer,
+            reporter: self.reporter.key(),
+            amount: slashed,
+            remaining_bond: claimer_bond.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
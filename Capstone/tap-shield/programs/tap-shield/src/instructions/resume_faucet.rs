@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, events::PolicyUpdated, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct ResumeFaucet<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> ResumeFaucet<'info> {
+    pub fn resume_faucet(&mut self) -> Result<()> {
+        self.faucet_registry.paused = false;
+
+        msg!("FAUCET: {} RESUMED", self.faucet_registry.name);
+
+        emit!(PolicyUpdated::new(
+            self.faucet_registry.key(),
+            &self.faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
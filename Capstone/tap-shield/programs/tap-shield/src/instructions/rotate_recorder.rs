@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct RotateRecorder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> RotateRecorder<'info> {
+    /// Swaps a recorder key in place so there is no window where neither key works.
+    pub fn rotate_recorder(&mut self, old_recorder: Pubkey, new_recorder: Pubkey) -> Result<()> {
+        let faucet_registry = &mut self.faucet_registry;
+
+        require!(
+            !faucet_registry.recorders.contains(&new_recorder),
+            TapShieldErr::RecorderAlreadyExists
+        );
+
+        let slot = faucet_registry
+            .recorders
+            .iter_mut()
+            .find(|key| **key == old_recorder)
+            .ok_or(TapShieldErr::RecorderNotFound)?;
+
+        *slot = new_recorder;
+
+        msg!(
+            "FAUCET: {} ROTATED RECORDER {} TO {}",
+            faucet_registry.name,
+            old_recorder,
+            new_recorder
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, events::PolicyUpdated, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct SetAllowlistOnly<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetAllowlistOnly<'info> {
+    pub fn set_allowlist_only(&mut self, allowlist_only: bool) -> Result<()> {
+        self.faucet_registry.allowlist_only = allowlist_only;
+
+        msg!(
+            "FAUCET: {} ALLOWLIST ONLY SET TO {}",
+            self.faucet_registry.name,
+            allowlist_only
+        );
+
+        emit!(PolicyUpdated::new(
+            self.faucet_registry.key(),
+            &self.faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{FaucetRegistry, MAX_ATTESTERS},
+};
+
+#[derive(Accounts)]
+pub struct SetAttesters<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetAttesters<'info> {
+    /// An empty list turns attestation checks off for the faucet.
+    pub fn set_attesters(&mut self, attesters: Vec<Pubkey>) -> Result<()> {
+        require!(
+            attesters.len() <= MAX_ATTESTERS,
+            TapShieldErr::TooManyAttesters
+        );
+
+        msg!(
+            "FAUCET: {} NOW TRUSTS {} ATTESTERS",
+            self.faucet_registry.name,
+            attesters.len()
+        );
+
+        self.faucet_registry.attesters = attesters;
+
+        Ok(())
+    }
+}
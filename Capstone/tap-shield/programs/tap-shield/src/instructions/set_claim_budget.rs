@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::PolicyUpdated,
+    states::{BudgetPeriod, ClaimBudget, FaucetRegistry},
+};
+
+#[derive(Accounts)]
+pub struct SetClaimBudget<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetClaimBudget<'info> {
+    /// `None` removes the cap. Amount already spent in the current period still counts
+    /// against a new cap of the same period.
+    pub fn set_claim_budget(&mut self, claim_budget: Option<ClaimBudget>) -> Result<()> {
+        if let Some(budget) = claim_budget {
+            require!(budget.max_amount > 0, TapShieldErr::InvalidBudget);
+
+            if let BudgetPeriod::Seconds(seconds) = budget.period {
+                require!(seconds > 0, TapShieldErr::InvalidBudget);
+            }
+        }
+
+        let faucet_registry = &mut self.faucet_registry;
+
+        faucet_registry.claim_budget = claim_budget;
+
+        msg!(
+            "FAUCET: {} BUDGET SET TO {:?} PER PERIOD",
+            faucet_registry.name,
+            claim_budget.map(|budget| budget.max_amount)
+        );
+
+        emit!(PolicyUpdated::new(
+            faucet_registry.key(),
+            faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
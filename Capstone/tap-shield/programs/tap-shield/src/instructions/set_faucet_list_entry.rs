@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{FaucetRegistry, ListKind, WalletListEntry, MAX_REASON_LEN},
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct SetFaucetListEntry<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = WalletListEntry::DISCRIMINATOR.len() + WalletListEntry::INIT_SPACE,
+        seeds = [b"list", faucet_registry.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub list_entry: Account<'info, WalletListEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetFaucetListEntry<'info> {
+    pub fn set_faucet_list_entry(
+        &mut self,
+        wallet: Pubkey,
+        kind: ListKind,
+        reason: String,
+        expires_at: Option<i64>,
+        bumps: &SetFaucetListEntryBumps,
+    ) -> Result<()> {
+        require!(reason.len() <= MAX_REASON_LEN, TapShieldErr::ReasonTooLong);
+
+        self.list_entry.set_inner(WalletListEntry {
+            version: WalletListEntry::VERSION,
+            wallet,
+            faucet: Some(self.faucet_registry.key()),
+            kind,
+            reason,
+            expires_at,
+            added_by: self.owner.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            bump: bumps.list_entry,
+            reserved: [0; 16],
+        });
+
+        msg!(
+            "FAUCET: {} LISTED WALLET {} UNTIL {:?}",
+            self.faucet_registry.name,
+            wallet,
+            expires_at
+        );
+
+        Ok(())
+    }
+}
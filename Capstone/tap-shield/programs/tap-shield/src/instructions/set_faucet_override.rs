@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::PolicyUpdated,
+    states::{FaucetRegistry, ProtocolConfig},
+};
+
+#[derive(Accounts)]
+pub struct SetFaucetOverride<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump,
+        has_one = admin @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetFaucetOverride<'info> {
+    /// `None` puts the faucet back on the network-wide cooldown from `ProtocolConfig`.
+    pub fn set_faucet_override(&mut self, global_cooldown_override: Option<i64>) -> Result<()> {
+        if let Some(cooldown) = global_cooldown_override {
+            require!(cooldown >= 0, TapShieldErr::InvalidCooldown);
+        }
+
+        self.faucet_registry.global_cooldown_override = global_cooldown_override;
+
+        msg!(
+            "FAUCET: {} GLOBAL COOLDOWN OVERRIDE SET TO {:?}",
+            self.faucet_registry.name,
+            global_cooldown_override
+        );
+
+        emit!(PolicyUpdated::new(
+            self.faucet_registry.key(),
+            &self.faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::PolicyUpdated,
+    states::{FaucetRegistry, ProtocolConfig},
+};
+
+#[derive(Accounts)]
+pub struct SetRegistryApproval<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump,
+        has_one = admin @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetRegistryApproval<'info> {
+    /// The user claim registry is shared by all faucets, so only faucets the admin approves
+    /// may update it for claims the claimer didn't sign.
+    pub fn set_registry_approval(&mut self, approved: bool) -> Result<()> {
+        self.faucet_registry.registry_approved = approved;
+
+        msg!(
+            "FAUCET: {} REGISTRY APPROVAL SET TO {}",
+            self.faucet_registry.name,
+            approved
+        );
+
+        emit!(PolicyUpdated::new(
+            self.faucet_registry.key(),
+            &self.faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::PolicyUpdated,
+    states::{FaucetRegistry, ProtocolConfig},
+};
+
+#[derive(Accounts)]
+pub struct SetReputationApproval<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = protocol_config.bump,
+        has_one = admin @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetReputationApproval<'info> {
+    /// Reputation is shared by all faucets, so only faucets the admin approves may reward
+    /// claims with it or flag claimers.
+    pub fn set_reputation_approval(&mut self, approved: bool) -> Result<()> {
+        self.faucet_registry.reputation_approved = approved;
+
+        msg!(
+            "FAUCET: {} REPUTATION APPROVAL SET TO {}",
+            self.faucet_registry.name,
+            approved
+        );
+
+        emit!(PolicyUpdated::new(
+            self.faucet_registry.key(),
+            &self.faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
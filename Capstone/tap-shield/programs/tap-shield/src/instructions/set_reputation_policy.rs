@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::PolicyUpdated,
+    states::{FaucetRegistry, ReputationPolicy, MAX_REPUTATION},
+};
+
+#[derive(Accounts)]
+pub struct SetReputationPolicy<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetReputationPolicy<'info> {
+    /// `None` lets every claimer through regardless of their score.
+    pub fn set_reputation_policy(
+        &mut self,
+        reputation_policy: Option<ReputationPolicy>,
+    ) -> Result<()> {
+        if let Some(policy) = reputation_policy {
+            require!(
+                policy.min_score <= MAX_REPUTATION,
+                TapShieldErr::InvalidInput
+            );
+        }
+
+        let faucet_registry = &mut self.faucet_registry;
+
+        faucet_registry.reputation_policy = reputation_policy;
+
+        msg!(
+            "FAUCET: {} MINIMUM REPUTATION SET TO {:?}",
+            faucet_registry.name,
+            reputation_policy.map(|policy| policy.min_score)
+        );
+
+        emit!(PolicyUpdated::new(
+            faucet_registry.key(),
+            faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, events::PolicyUpdated, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct SetRequiredBond<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> SetRequiredBond<'info> {
+    /// `0` turns bonded mode off.
+    pub fn set_required_bond(&mut self, required_bond: u64) -> Result<()> {
+        let faucet_registry = &mut self.faucet_registry;
+
+        faucet_registry.required_bond = required_bond;
+
+        msg!(
+            "FAUCET: {} REQUIRED BOND SET TO {} LAMPORTS",
+            faucet_registry.name,
+            required_bond
+        );
+
+        emit!(PolicyUpdated::new(
+            faucet_registry.key(),
+            faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, events::OwnershipTransferProposed, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct TransferFaucetOwnership<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> TransferFaucetOwnership<'info> {
+    /// First step of the handoff; ownership only moves once `new_owner` accepts.
+    /// Passing `None` cancels a pending transfer.
+    pub fn transfer_faucet_ownership(&mut self, new_owner: Option<Pubkey>) -> Result<()> {
+        self.faucet_registry.pending_owner = new_owner;
+
+        msg!(
+            "FAUCET: {} PENDING OWNER SET TO {:?}",
+            self.faucet_registry.name,
+            new_owner
+        );
+
+        emit!(OwnershipTransferProposed {
+            faucet: self.faucet_registry.key(),
+            owner: self.owner.key(),
+            pending_owner: new_owner,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
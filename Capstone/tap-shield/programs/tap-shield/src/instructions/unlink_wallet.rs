@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    states::{ClusterMembership, FaucetRegistry, WalletCluster},
+};
+
+#[derive(Accounts)]
+pub struct UnlinkWallet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.is_cluster_linker(&authority.key()) @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"cluster_member", faucet_registry.key().as_ref(), cluster_membership.wallet.as_ref()],
+        bump = cluster_membership.bump
+    )]
+    pub cluster_membership: Account<'info, ClusterMembership>,
+
+    #[account(
+        mut,
+        address = cluster_membership.cluster @ TapShieldErr::ClusterMismatch
+    )]
+    pub wallet_cluster: Account<'info, WalletCluster>,
+}
+
+impl<'info> UnlinkWallet<'info> {
+    /// The cluster keeps its last claim time; only future claims of the wallet stop
+    /// counting towards it. Unlinking the last member closes the cluster as well.
+    pub fn unlink_wallet(&mut self) -> Result<()> {
+        let wallet_cluster = &mut self.wallet_cluster;
+
+        wallet_cluster.member_count = wallet_cluster.member_count.saturating_sub(1);
+
+        msg!(
+            "WALLET {} UNLINKED FROM CLUSTER {}. MEMBERS: {}",
+            self.cluster_membership.wallet,
+            wallet_cluster.cluster_id,
+            wallet_cluster.member_count
+        );
+
+        if wallet_cluster.member_count == 0 {
+            msg!("CLUSTER {} IS EMPTY AND CLOSED", wallet_cluster.cluster_id);
+
+            wallet_cluster.close(self.authority.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::TapShieldErr, states::ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = protocol_config.bump,
+        has_one = admin @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_config(&mut self, global_cooldown_seconds: i64) -> Result<()> {
+        require!(global_cooldown_seconds >= 0, TapShieldErr::InvalidCooldown);
+
+        self.protocol_config.global_cooldown_seconds = global_cooldown_seconds;
+
+        msg!(
+            "GLOBAL COOLDOWN UPDATED TO {} SECONDS",
+            global_cooldown_seconds
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::FaucetMetadataUpdated,
+    migration::resize_with_rent,
+    states::{FaucetRegistry, MAX_CATEGORY_LEN, MAX_CONTACT_LEN, MAX_URI_LEN},
+};
+
+/// Keeps the registry sized to the current layout; any growth is paid by the owner.
+#[derive(Accounts)]
+pub struct UpdateFaucetMetadata<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Registries created before fields were appended to the layout are too short to
+    /// deserialize, so the handler checks the fixed-offset header, then grows the account.
+    #[account(mut, owner = crate::ID)]
+    ///CHECK: checked against its seeds and owner in the handler before it is resized
+    pub faucet_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateFaucetMetadata<'info> {
+    /// Fields passed as `None` keep their current value.
+    pub fn update_faucet_metadata(
+        &mut self,
+        name: Option<String>,
+        uri: Option<String>,
+        category: Option<String>,
+        contact: Option<String>,
+    ) -> Result<()> {
+        let info = self.faucet_registry.to_account_info();
+        let space = FaucetRegistry::DISCRIMINATOR.len() + FaucetRegistry::INIT_SPACE;
+
+        // Seeds and owner are checked before anything is paid for or resized. `faucet_id`
+        // and `owner` sit at fixed offsets in every versioned layout, unlike the trailing
+        // `bump`; legacy registries fail the seed check until they are migrated.
+        {
+            let data = info.try_borrow_data()?;
+
+            require!(
+                data.starts_with(FaucetRegistry::DISCRIMINATOR),
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+
+            let (_version, faucet_id, owner) = <(u8, Pubkey, Pubkey)>::deserialize(
+                &mut &data[FaucetRegistry::DISCRIMINATOR.len()..],
+            )?;
+            let (address, _) =
+                Pubkey::find_program_address(&[b"faucet", faucet_id.as_ref()], &crate::ID);
+
+            require_keys_eq!(address, info.key(), ErrorCode::ConstraintSeeds);
+            require_keys_eq!(owner, self.owner.key(), TapShieldErr::UnauthorizedFaucet);
+        }
+
+        if info.data_len() < space {
+            resize_with_rent(&info, space, &self.owner, &self.system_program)?;
+        }
+
+        let mut faucet_registry =
+            FaucetRegistry::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+        if let Some(name) = name {
+            require!(name.len() <= 32, TapShieldErr::FaucetNameTooLong);
+            require!(!name.is_empty(), TapShieldErr::InvalidInput);
+
+            faucet_registry.name = name;
+        }
+
+        if let Some(uri) = uri {
+            require!(uri.len() <= MAX_URI_LEN, TapShieldErr::MetadataTooLong);
+
+            faucet_registry.uri = uri;
+        }
+
+        if let Some(category) = category {
+            require!(
+                category.len() <= MAX_CATEGORY_LEN,
+                TapShieldErr::MetadataTooLong
+            );
+
+            faucet_registry.category = category;
+        }
+
+        if let Some(contact) = contact {
+            require!(
+                contact.len() <= MAX_CONTACT_LEN,
+                TapShieldErr::MetadataTooLong
+            );
+
+            faucet_registry.contact = contact;
+        }
+
+        faucet_registry.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        msg!("FAUCET: {} METADATA UPDATED", faucet_registry.name);
+
+        emit!(FaucetMetadataUpdated {
+            faucet: info.key(),
+            name: faucet_registry.name.clone(),
+            uri: faucet_registry.uri.clone(),
+            category: faucet_registry.category.clone(),
+            contact: faucet_registry.contact.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
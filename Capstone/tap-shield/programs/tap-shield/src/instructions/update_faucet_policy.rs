@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    events::PolicyUpdated,
+    states::{ClaimQuota, FaucetRegistry, RECENT_CLAIMS_LEN},
+};
+
+#[derive(Accounts)]
+pub struct UpdateFaucetPolicy<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+}
+
+impl<'info> UpdateFaucetPolicy<'info> {
+    pub fn update_faucet_policy(
+        &mut self,
+        cooldown_seconds: i64,
+        claim_quota: Option<ClaimQuota>,
+        require_claimer_signature: bool,
+    ) -> Result<()> {
+        require!(cooldown_seconds > 0, TapShieldErr::InvalidCooldown);
+
+        if let Some(quota) = claim_quota {
+            require!(quota.window_seconds > 0, TapShieldErr::InvalidQuota);
+            require!(
+                quota.max_claims > 0 && quota.max_claims as usize <= RECENT_CLAIMS_LEN,
+                TapShieldErr::InvalidQuota
+            );
+        }
+
+        let faucet_registry = &mut self.faucet_registry;
+        let previous = faucet_registry.cooldown_seconds;
+
+        faucet_registry.cooldown_seconds = cooldown_seconds;
+        faucet_registry.claim_quota = claim_quota;
+        faucet_registry.require_claimer_signature = require_claimer_signature;
+
+        msg!(
+            "FAUCET: {} COOLDOWN UPDATED FROM {} TO {} SECONDS",
+            faucet_registry.name,
+            previous,
+            cooldown_seconds
+        );
+
+        emit!(PolicyUpdated::new(
+            faucet_registry.key(),
+            faucet_registry,
+            Clock::get()?.unix_timestamp
+        ));
+
+        Ok(())
+    }
+}
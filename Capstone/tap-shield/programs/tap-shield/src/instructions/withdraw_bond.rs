@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TapShieldErr,
+    guard::load_optional,
+    states::{ClaimerBond, UserClaimRegistry, BOND_LOCK_SECONDS},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawBond<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", claimer.key().as_ref()],
+        bump = claimer_bond.bump,
+        has_one = claimer @ TapShieldErr::UnauthorizedSigner
+    )]
+    pub claimer_bond: Account<'info, ClaimerBond>,
+
+    #[account(
+        seeds = [b"user_registry", claimer.key().as_ref()],
+        bump
+    )]
+    ///CHECK: claimer's user registry, may not exist if they never claimed
+    pub user_claim_registry: UncheckedAccount<'info>,
+}
+
+impl<'info> WithdrawBond<'info> {
+    /// Withdrawing the whole bond closes the account and also returns its rent.
+    pub fn withdraw_bond(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, TapShieldErr::InvalidInput);
+        require!(
+            amount <= self.claimer_bond.amount,
+            TapShieldErr::InsufficientBond
+        );
+        require!(
+            self.claimer_bond.pending_reports == 0,
+            TapShieldErr::BondLocked
+        );
+
+        if let Some(registry) = load_optional::<UserClaimRegistry>(&self.user_claim_registry)? {
+            let unlocks_at = registry
+                .last_claim_timestamp
+                .checked_add(BOND_LOCK_SECONDS)
+                .ok_or(TapShieldErr::InvalidTimestamp)?;
+
+            require!(
+                Clock::get()?.unix_timestamp >= unlocks_at,
+                TapShieldErr::BondLocked
+            );
+        }
+
+        if amount == self.claimer_bond.amount {
+            self.claimer_bond.close(self.claimer.to_account_info())?;
+
+            msg!("BOND OF {} WITHDRAWN AND CLOSED", self.claimer.key());
+
+            return Ok(());
+        }
+
+        self.claimer_bond.sub_lamports(amount)?;
+        self.claimer.add_lamports(amount)?;
+        self.claimer_bond.amount -= amount;
+
+        msg!(
+            "WITHDREW {} LAMPORTS FROM BOND OF {}. REMAINING: {}",
+            amount,
+            self.claimer.key(),
+            self.claimer_bond.amount
+        );
+
+        Ok(())
+    }
+}
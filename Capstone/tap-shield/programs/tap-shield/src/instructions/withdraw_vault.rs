@@ -0,0 +1,59 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{errors::TapShieldErr, guard::check_vault_payout, states::FaucetRegistry};
+
+#[derive(Accounts)]
+pub struct WithdrawVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"faucet", faucet_registry.faucet_id.as_ref()],
+        bump = faucet_registry.bump,
+        constraint = faucet_registry.owner == owner.key() @ TapShieldErr::UnauthorizedFaucet
+    )]
+    pub faucet_registry: Account<'info, FaucetRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", faucet_registry.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawVault<'info> {
+    pub fn withdraw_vault(&mut self, amount: u64, bumps: &WithdrawVaultBumps) -> Result<()> {
+        require!(amount > 0, TapShieldErr::InvalidInput);
+        check_vault_payout(&self.vault, amount)?;
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.owner.to_account_info(),
+        };
+
+        let faucet_key = self.faucet_registry.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[b"vault", faucet_key.as_ref(), &[bumps.vault]]];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer(cpi_ctx, amount)?;
+
+        msg!(
+            "WITHDREW {} LAMPORTS FROM VAULT OF {}",
+            amount,
+            self.faucet_registry.name
+        );
+
+        Ok(())
+    }
+}
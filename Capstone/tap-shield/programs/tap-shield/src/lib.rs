@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
 
+pub mod attestation;
 pub mod errors;
+pub mod events;
+pub mod guard;
 pub mod instructions;
+pub mod migration;
 pub mod states;
 
 use crate::instructions::*;
+use crate::states::{ClaimBudget, ClaimQuota, ListKind, ReputationFlag, ReputationPolicy};
 
 declare_id!("EY3vvz2h9otDW1icM9tZefmaE6WCkGbNbif8wTC1TR4X");
 
@@ -12,17 +17,276 @@ declare_id!("EY3vvz2h9otDW1icM9tZefmaE6WCkGbNbif8wTC1TR4X");
 pub mod tap_shield {
     use super::*;
 
-    pub fn initialize_faucet(ctx: Context<InitializeFaucet>, name: String) -> Result<()> {
-        ctx.accounts.initialize_faucet(name)
+    pub fn initialize_faucet(
+        ctx: Context<InitializeFaucet>,
+        faucet_id: Pubkey,
+        name: String,
+        cooldown_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_faucet(faucet_id, name, cooldown_seconds, &ctx.bumps)
     }
 
     pub fn record_claim(
         ctx: Context<RecordClaim>,
         claimer_pubkey: Pubkey,
         amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .record_claim(claimer_pubkey, amount, &ctx.bumps)
+    }
+
+    pub fn update_faucet_policy(
+        ctx: Context<UpdateFaucetPolicy>,
         cooldown_seconds: i64,
+        claim_quota: Option<ClaimQuota>,
+        require_claimer_signature: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_faucet_policy(cooldown_seconds, claim_quota, require_claimer_signature)
+    }
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        global_cooldown_seconds: i64,
     ) -> Result<()> {
         ctx.accounts
-            .record_claim(claimer_pubkey, amount, cooldown_seconds)
+            .initialize_config(global_cooldown_seconds, &ctx.bumps)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, global_cooldown_seconds: i64) -> Result<()> {
+        ctx.accounts.update_config(global_cooldown_seconds)
+    }
+
+    pub fn set_faucet_override(
+        ctx: Context<SetFaucetOverride>,
+        global_cooldown_override: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts.set_faucet_override(global_cooldown_override)
+    }
+
+    pub fn deposit_vault(ctx: Context<DepositVault>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_vault(amount)
+    }
+
+    pub fn withdraw_vault(ctx: Context<WithdrawVault>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_vault(amount, &ctx.bumps)
+    }
+
+    pub fn claim(ctx: Context<Claim>, amount: u64) -> Result<()> {
+        ctx.accounts.claim(amount, &ctx.bumps)
+    }
+
+    pub fn bind_faucet_mint(ctx: Context<BindFaucetMint>) -> Result<()> {
+        ctx.accounts.bind_faucet_mint()
+    }
+
+    pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_tokens(amount)
+    }
+
+    pub fn withdraw_tokens(ctx: Context<WithdrawTokens>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_tokens(amount, &ctx.bumps)
+    }
+
+    pub fn claim_tokens(ctx: Context<ClaimTokens>, amount: u64) -> Result<()> {
+        ctx.accounts.claim_tokens(amount, &ctx.bumps)
+    }
+
+    pub fn set_attesters(ctx: Context<SetAttesters>, attesters: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.set_attesters(attesters)
+    }
+
+    pub fn add_recorder(ctx: Context<AddRecorder>, recorder: Pubkey) -> Result<()> {
+        ctx.accounts.add_recorder(recorder)
+    }
+
+    pub fn remove_recorder(ctx: Context<RemoveRecorder>, recorder: Pubkey) -> Result<()> {
+        ctx.accounts.remove_recorder(recorder)
+    }
+
+    pub fn rotate_recorder(
+        ctx: Context<RotateRecorder>,
+        old_recorder: Pubkey,
+        new_recorder: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.rotate_recorder(old_recorder, new_recorder)
+    }
+
+    pub fn transfer_faucet_ownership(
+        ctx: Context<TransferFaucetOwnership>,
+        new_owner: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.transfer_faucet_ownership(new_owner)
+    }
+
+    pub fn accept_faucet_ownership(ctx: Context<AcceptFaucetOwnership>) -> Result<()> {
+        ctx.accounts.accept_faucet_ownership()
+    }
+
+    pub fn add_protocol_block(
+        ctx: Context<AddProtocolBlock>,
+        wallet: Pubkey,
+        reason: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .add_protocol_block(wallet, reason, expires_at, &ctx.bumps)
+    }
+
+    pub fn remove_protocol_block(ctx: Context<RemoveProtocolBlock>) -> Result<()> {
+        ctx.accounts.remove_protocol_block()
+    }
+
+    pub fn set_faucet_list_entry(
+        ctx: Context<SetFaucetListEntry>,
+        wallet: Pubkey,
+        kind: ListKind,
+        reason: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_faucet_list_entry(wallet, kind, reason, expires_at, &ctx.bumps)
+    }
+
+    pub fn remove_faucet_list_entry(ctx: Context<RemoveFaucetListEntry>) -> Result<()> {
+        ctx.accounts.remove_faucet_list_entry()
+    }
+
+    pub fn set_allowlist_only(ctx: Context<SetAllowlistOnly>, allowlist_only: bool) -> Result<()> {
+        ctx.accounts.set_allowlist_only(allowlist_only)
+    }
+
+    pub fn close_claim_records<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseClaimRecords<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.close_claim_records(ctx.remaining_accounts)
+    }
+
+    pub fn set_claim_budget(
+        ctx: Context<SetClaimBudget>,
+        claim_budget: Option<ClaimBudget>,
+    ) -> Result<()> {
+        ctx.accounts.set_claim_budget(claim_budget)
+    }
+
+    pub fn bind_faucet_program(
+        ctx: Context<BindFaucetProgram>,
+        program_id: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.bind_faucet_program(program_id)
+    }
+
+    pub fn check_and_record(ctx: Context<CheckAndRecord>, amount: u64) -> Result<()> {
+        ctx.accounts.check_and_record(amount, &ctx.bumps)
+    }
+
+    pub fn pause_faucet(ctx: Context<PauseFaucet>) -> Result<()> {
+        ctx.accounts.pause_faucet()
+    }
+
+    pub fn resume_faucet(ctx: Context<ResumeFaucet>) -> Result<()> {
+        ctx.accounts.resume_faucet()
+    }
+
+    pub fn close_faucet(ctx: Context<CloseFaucet>) -> Result<()> {
+        ctx.accounts.close_faucet(&ctx.bumps)
+    }
+
+    pub fn update_faucet_metadata(
+        ctx: Context<UpdateFaucetMetadata>,
+        name: Option<String>,
+        uri: Option<String>,
+        category: Option<String>,
+        contact: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_faucet_metadata(name, uri, category, contact)
+    }
+
+    pub fn migrate_faucet_registry(
+        ctx: Context<MigrateFaucetRegistry>,
+        cooldown_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.migrate_faucet_registry(cooldown_seconds)
+    }
+
+    pub fn migrate_user_claim_registry(ctx: Context<MigrateUserClaimRegistry>) -> Result<()> {
+        ctx.accounts.migrate_user_claim_registry()
+    }
+
+    pub fn migrate_claim_record(ctx: Context<MigrateClaimRecord>) -> Result<()> {
+        ctx.accounts.migrate_claim_record()
+    }
+
+    pub fn set_required_bond(ctx: Context<SetRequiredBond>, required_bond: u64) -> Result<()> {
+        ctx.accounts.set_required_bond(required_bond)
+    }
+
+    pub fn deposit_bond(ctx: Context<DepositBond>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_bond(amount, &ctx.bumps)
+    }
+
+    pub fn withdraw_bond(ctx: Context<WithdrawBond>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_bond(amount)
+    }
+
+    pub fn report_abuse(ctx: Context<ReportAbuse>, amount: u64, reason: String) -> Result<()> {
+        ctx.accounts.report_abuse(amount, reason, &ctx.bumps)
+    }
+
+    pub fn resolve_slash_report(ctx: Context<ResolveSlashReport>, approve: bool) -> Result<()> {
+        ctx.accounts.resolve_slash_report(approve)
+    }
+
+    pub fn link_wallet(ctx: Context<LinkWallet>, cluster_id: Pubkey, wallet: Pubkey) -> Result<()> {
+        ctx.accounts.link_wallet(cluster_id, wallet, &ctx.bumps)
+    }
+
+    pub fn unlink_wallet(ctx: Context<UnlinkWallet>) -> Result<()> {
+        ctx.accounts.unlink_wallet()
+    }
+
+    pub fn set_reputation_policy(
+        ctx: Context<SetReputationPolicy>,
+        reputation_policy: Option<ReputationPolicy>,
+    ) -> Result<()> {
+        ctx.accounts.set_reputation_policy(reputation_policy)
+    }
+
+    pub fn flag_claimer(ctx: Context<FlagClaimer>, flag: ReputationFlag) -> Result<()> {
+        ctx.accounts.flag_claimer(flag, &ctx.bumps)
+    }
+
+    pub fn check_eligibility(ctx: Context<CheckEligibility>, amount: u64) -> Result<Eligibility> {
+        ctx.accounts.check_eligibility(amount)
+    }
+
+    pub fn record_claims_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordClaimsBatch<'info>>,
+        claims: Vec<BatchClaim>,
+        mode: BatchMode,
+    ) -> Result<()> {
+        ctx.accounts
+            .record_claims_batch(claims, mode, ctx.remaining_accounts)
+    }
+
+    pub fn init_claim_log(ctx: Context<InitClaimLog>) -> Result<()> {
+        ctx.accounts.init_claim_log()
+    }
+
+    pub fn close_claim_log(ctx: Context<CloseClaimLog>) -> Result<()> {
+        ctx.accounts.close_claim_log()
+    }
+
+    pub fn set_reputation_approval(
+        ctx: Context<SetReputationApproval>,
+        approved: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_reputation_approval(approved)
+    }
+
+    pub fn set_registry_approval(ctx: Context<SetRegistryApproval>, approved: bool) -> Result<()> {
+        ctx.accounts.set_registry_approval(approved)
     }
 }
@@ -0,0 +1,79 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+
+use crate::errors::TapShieldErr;
+
+/// Reads a pre-versioning account of type `T` as its legacy layout `L`. Accounts are told
+/// apart by size: legacy ones still have their original `legacy_space`, while versioned
+/// ones are larger and start with their `version` byte.
+pub fn read_legacy<T: Discriminator, L: AnchorDeserialize>(
+    info: &AccountInfo,
+    legacy_space: usize,
+    current_version: u8,
+) -> Result<L> {
+    let data = info.try_borrow_data()?;
+
+    require!(
+        data.len() >= T::DISCRIMINATOR.len() && data.starts_with(T::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let body = &data[T::DISCRIMINATOR.len()..];
+
+    if body.len() != legacy_space {
+        return match body.first() {
+            Some(&version) if version == current_version => {
+                err!(TapShieldErr::AccountAlreadyMigrated)
+            }
+            _ => err!(TapShieldErr::UnsupportedAccountVersion),
+        };
+    }
+
+    Ok(L::deserialize(&mut &body[..])?)
+}
+
+/// Grows `info` to `space`, topping up rent from `payer`, and writes `account` over it.
+pub fn write_migrated<'info, T: AccountSerialize>(
+    account: &T,
+    space: usize,
+    info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    resize_with_rent(info, space, payer, system_program)?;
+
+    let mut data = info.try_borrow_mut_data()?;
+
+    account.try_serialize(&mut &mut data[..])
+}
+
+/// Resizes `info` to `space`, topping up rent from `payer`. Bytes added at the end are
+/// zeroed, so fields appended to a layout read as zero, `None` or `false`.
+pub fn resize_with_rent<'info>(
+    info: &AccountInfo<'info>,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(info.lamports());
+
+    if top_up > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: info.clone(),
+            },
+        );
+
+        transfer(cpi_ctx, top_up)?;
+    }
+
+    info.resize(space)?;
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// Entries a `ClaimLog` holds before it starts overwriting the oldest ones.
+pub const CLAIM_LOG_CAPACITY: usize = 1024;
+
+/// Ring buffer of a faucet's most recent claims. Too large for a PDA created through CPI,
+/// so the owner allocates it as a keypair account and hands it to `init_claim_log`. While
+/// a faucet has one, its claims go here instead of into per-claim `ClaimRecord`s.
+#[account(zero_copy)]
+pub struct ClaimLog {
+    pub faucet: Pubkey,
+    /// Claims ever appended; the next one goes to `total_entries % CLAIM_LOG_CAPACITY`.
+    pub total_entries: u64,
+    pub version: u8,
+    pub reserved: [u8; 7],
+    pub entries: [ClaimLogEntry; CLAIM_LOG_CAPACITY],
+}
+
+#[zero_copy]
+pub struct ClaimLogEntry {
+    pub claimer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+impl ClaimLog {
+    pub const VERSION: u8 = 1;
+    pub const SPACE: usize = 8 + std::mem::size_of::<ClaimLog>();
+
+    pub fn push(&mut self, entry: ClaimLogEntry) {
+        let index = (self.total_entries % CLAIM_LOG_CAPACITY as u64) as usize;
+
+        self.entries[index] = entry;
+        self.total_entries += 1;
+    }
+
+    /// Entries still in the buffer, oldest first.
+    pub fn recent(&self) -> impl Iterator<Item = &ClaimLogEntry> {
+        let len = self.total_entries.min(CLAIM_LOG_CAPACITY as u64) as usize;
+        let start = (self.total_entries as usize).wrapping_sub(len) % CLAIM_LOG_CAPACITY;
+
+        (0..len).map(move |offset| &self.entries[(start + offset) % CLAIM_LOG_CAPACITY])
+    }
+}
@@ -3,8 +3,14 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct ClaimRecord {
+    pub version: u8,
     pub claimer: Pubkey,
     pub faucet_id: Pubkey,
     pub amount: u64,
     pub timestamp: i64,
+    pub reserved: [u8; 16],
+}
+
+impl ClaimRecord {
+    pub const VERSION: u8 = 1;
 }
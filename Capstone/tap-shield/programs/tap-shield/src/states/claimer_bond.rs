@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// How long after their last claim a claimer has to wait before withdrawing, so that
+/// operators have time to report abuse.
+pub const BOND_LOCK_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Refundable SOL bond at `["bond", claimer]`. `amount` excludes the rent-exempt minimum,
+/// which is only returned when the bond is fully withdrawn.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimerBond {
+    pub version: u8,
+    pub claimer: Pubkey,
+    pub amount: u64,
+    pub pending_reports: u32,
+    pub deposited_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 32],
+}
+
+impl ClaimerBond {
+    pub const VERSION: u8 = 1;
+}
@@ -1,11 +1,152 @@
 use anchor_lang::prelude::*;
 
+use crate::states::{ProtocolConfig, MAX_REPUTATION};
+
+pub const MAX_ATTESTERS: usize = 4;
+pub const MAX_RECORDERS: usize = 8;
+pub const MAX_URI_LEN: usize = 128;
+pub const MAX_CATEGORY_LEN: usize = 32;
+pub const MAX_CONTACT_LEN: usize = 64;
+
 #[account]
 #[derive(InitSpace)]
 pub struct FaucetRegistry {
-    pub operator: Pubkey,
+    pub version: u8,
+    pub faucet_id: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    #[max_len(MAX_RECORDERS)]
+    pub recorders: Vec<Pubkey>,
     #[max_len(32)]
     pub name: String,
     pub total_claims: u64,
     pub created_at: i64,
+    pub cooldown_seconds: i64,
+    pub global_cooldown_override: Option<i64>,
+    pub claim_quota: Option<ClaimQuota>,
+    pub mint: Option<Pubkey>,
+    pub require_claimer_signature: bool,
+    #[max_len(MAX_ATTESTERS)]
+    pub attesters: Vec<Pubkey>,
+    pub allowlist_only: bool,
+    pub closed_claims: u64,
+    pub closed_amount: u64,
+    pub total_amount: u64,
+    pub unique_claimers: u64,
+    pub claim_budget: Option<ClaimBudget>,
+    pub budget_period: u64,
+    pub budget_spent: u64,
+    pub program_binding: Option<ProgramBinding>,
+    pub paused: bool,
+    #[max_len(MAX_URI_LEN)]
+    pub uri: String,
+    /// Free-form network/category tag, e.g. "devnet" or "testnet-usdc".
+    #[max_len(MAX_CATEGORY_LEN)]
+    pub category: String,
+    #[max_len(MAX_CONTACT_LEN)]
+    pub contact: String,
+    pub bump: u8,
+    /// Lamports a claimer must keep bonded in their `ClaimerBond` to claim; 0 disables it.
+    pub required_bond: u64,
+    pub reputation_policy: Option<ReputationPolicy>,
+    /// `ClaimLog` that claims are appended to instead of creating `ClaimRecord`s.
+    pub claim_log: Option<Pubkey>,
+    /// Set by the protocol admin; only approved faucets reward or flag reputation.
+    pub reputation_approved: bool,
+    /// Open `SlashReport`s filed by this faucet; it can't be closed while any are pending.
+    pub pending_slash_reports: u16,
+    /// `ClaimRecord`s not yet closed. The faucet can only be closed without any, so a
+    /// re-registered faucet never reuses the claim index of an old record.
+    pub open_claim_records: u64,
+    /// Set by the protocol admin; unsigned claims at approved faucets still update the
+    /// claimer's `UserClaimRegistry`.
+    pub registry_approved: bool,
+    /// Zeroed space that future fields are carved from, so upgrades keep the account size.
+    pub reserved: [u8; 64],
+}
+
+impl FaucetRegistry {
+    pub const VERSION: u8 = 1;
+
+    /// The owner can always record claims; recorders are hot keys added by the owner.
+    pub fn is_recorder(&self, key: &Pubkey) -> bool {
+        self.owner == *key || self.recorders.contains(key)
+    }
+
+    /// Operators and attesters may link wallets into clusters.
+    pub fn is_cluster_linker(&self, key: &Pubkey) -> bool {
+        self.is_recorder(key) || self.attesters.contains(key)
+    }
+
+    pub fn global_cooldown(&self, protocol_config: &ProtocolConfig) -> i64 {
+        self.global_cooldown_override
+            .unwrap_or(protocol_config.global_cooldown_seconds)
+    }
+
+    /// Longest look-back used by any claim check; older claim records can be pruned.
+    pub fn history_window(&self, protocol_config: &ProtocolConfig) -> i64 {
+        let quota_window = self.claim_quota.map_or(0, |quota| quota.window_seconds);
+
+        self.cooldown_seconds
+            .max(self.global_cooldown(protocol_config))
+            .max(quota_window)
+    }
+}
+
+/// Rolling-window limit checked against the claimer's most recent claims at this faucet.
+/// `max_amount` is in the faucet's unit (lamports, or base units of its mint); 0 leaves the
+/// cumulative amount unbounded.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ClaimQuota {
+    pub max_claims: u8,
+    pub max_amount: u64,
+    pub window_seconds: i64,
+}
+
+/// Graduated claim limits based on the claimer's `UserClaimRegistry` reputation score.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ReputationPolicy {
+    /// Claimers scoring below this are rejected.
+    pub min_score: u16,
+    /// Largest claim at `MAX_REPUTATION`; lower scores get a proportional share. 0 means
+    /// the amount is not scaled.
+    pub max_amount: u64,
+}
+
+impl ReputationPolicy {
+    pub fn max_amount_for(&self, score: u16) -> Option<u64> {
+        (self.max_amount > 0)
+            .then(|| (self.max_amount as u128 * score as u128 / MAX_REPUTATION as u128) as u64)
+    }
+}
+
+/// Cap on the total amount a faucet disburses per period; unspent budget does not carry over.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ClaimBudget {
+    pub max_amount: u64,
+    pub period: BudgetPeriod,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BudgetPeriod {
+    /// Fixed windows of this many seconds, aligned to the unix epoch (86400 = UTC days).
+    Seconds(i64),
+    /// One Solana epoch.
+    Epoch,
+}
+
+impl ClaimBudget {
+    pub fn current_period(&self, clock: &Clock) -> u64 {
+        match self.period {
+            BudgetPeriod::Seconds(seconds) => clock.unix_timestamp.div_euclid(seconds) as u64,
+            BudgetPeriod::Epoch => clock.epoch,
+        }
+    }
+}
+
+/// On-chain program allowed to call `check_and_record` for this faucet, and the PDA it signs with.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ProgramBinding {
+    pub program_id: Pubkey,
+    pub authority: Pubkey,
 }
@@ -0,0 +1,30 @@
+//! Layouts written before accounts carried a `version` byte (the original deployment).
+//! They share the discriminators of the current accounts and are only read by `migrate_*`.
+
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyFaucetRegistry {
+    pub operator: Pubkey,
+    #[max_len(32)]
+    pub name: String,
+    pub total_claims: u64,
+    pub created_at: i64,
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyUserClaimRegistry {
+    pub user: Pubkey,
+    pub last_claim_timestamp: i64,
+    pub last_faucet: Pubkey,
+    pub total_claims_across_faucets: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyClaimRecord {
+    pub claimer: Pubkey,
+    pub faucet_id: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -5,4 +5,28 @@ pub mod faucet_registry;
 pub use faucet_registry::*;
 
 pub mod user_claim_registry;
-pub use user_claim_registry::*;
\ No newline at end of file
+pub use user_claim_registry::*;
+
+pub mod protocol_config;
+pub use protocol_config::*;
+
+pub mod wallet_list_entry;
+pub use wallet_list_entry::*;
+
+pub mod user_faucet_state;
+pub use user_faucet_state::*;
+
+pub mod legacy;
+pub use legacy::*;
+
+pub mod claimer_bond;
+pub use claimer_bond::*;
+
+pub mod slash_report;
+pub use slash_report::*;
+
+pub mod wallet_cluster;
+pub use wallet_cluster::*;
+
+pub mod claim_log;
+pub use claim_log::*;
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    pub version: u8,
+    pub admin: Pubkey,
+    pub global_cooldown_seconds: i64,
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+impl ProtocolConfig {
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::states::MAX_REASON_LEN;
+
+/// Abuse report against a claimer's bond at `["slash", faucet_registry, claimer]`, filed by
+/// a faucet operator and resolved by the protocol admin.
+#[account]
+#[derive(InitSpace)]
+pub struct SlashReport {
+    pub version: u8,
+    pub faucet: Pubkey,
+    pub claimer: Pubkey,
+    pub reporter: Pubkey,
+    pub amount: u64,
+    #[max_len(MAX_REASON_LEN)]
+    pub reason: String,
+    pub created_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+impl SlashReport {
+    pub const VERSION: u8 = 1;
+}
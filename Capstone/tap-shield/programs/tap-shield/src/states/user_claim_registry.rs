@@ -1,11 +1,60 @@
 use anchor_lang::prelude::*;
 
+/// Upper bound of the reputation score; the lower bound is 0.
+pub const MAX_REPUTATION: u16 = 1000;
+/// Score of a wallet that was never rewarded or flagged.
+pub const INITIAL_REPUTATION: u16 = 500;
+/// Earned by a claim that comes at least twice the faucet cooldown after the previous one.
+pub const WELL_SPACED_CLAIM_REWARD: u16 = 10;
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserClaimRegistry {
+    pub version: u8,
     pub user: Pubkey,
     pub last_claim_timestamp: i64,
     pub last_faucet: Pubkey,
     pub total_claims_across_faucets: u64,
     pub bump: u8,
+    /// Claim records of this wallet closed at any faucet, and the amount they recorded.
+    pub closed_claims: u64,
+    pub closed_amount: u64,
+    /// `None` until the first reward or flag; read it through `reputation()`.
+    pub reputation_score: Option<u16>,
+    pub reserved: [u8; 61],
+}
+
+/// Reason an operator lowers a claimer's reputation through `flag_claimer`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReputationFlag {
+    /// The claimer tried to claim and was turned away (cooldown, quota, list checks...).
+    RejectedAttempt,
+    /// The operator caught the claimer farming or otherwise abusing the faucet.
+    Abuse,
+}
+
+impl ReputationFlag {
+    pub fn penalty(&self) -> u16 {
+        match self {
+            ReputationFlag::RejectedAttempt => 25,
+            ReputationFlag::Abuse => 100,
+        }
+    }
+}
+
+impl UserClaimRegistry {
+    pub const VERSION: u8 = 1;
+
+    pub fn reputation(&self) -> u16 {
+        self.reputation_score.unwrap_or(INITIAL_REPUTATION)
+    }
+
+    /// Adds `delta` to the score, clamped to `0..=MAX_REPUTATION`.
+    pub fn adjust_reputation(&mut self, delta: i32) -> u16 {
+        let score = (self.reputation() as i32 + delta).clamp(0, MAX_REPUTATION as i32) as u16;
+
+        self.reputation_score = Some(score);
+
+        score
+    }
 }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+/// Number of most recent claims kept for rolling-window quota checks; also the largest
+/// `max_claims` a quota can have, so a full window is always noticed.
+pub const RECENT_CLAIMS_LEN: usize = 8;
+/// How many times one faucet may flag the same claimer through `flag_claimer`.
+pub const MAX_FLAGS_PER_FAUCET: u8 = 3;
+
+/// Per-(claimer, faucet) state; created on the claimer's first claim at the faucet.
+#[account]
+#[derive(InitSpace)]
+pub struct UserFaucetState {
+    pub version: u8,
+    pub user: Pubkey,
+    pub faucet: Pubkey,
+    pub first_claim_timestamp: i64,
+    pub bump: u8,
+    pub last_claim_timestamp: i64,
+    pub claim_count: u64,
+    pub total_amount: u64,
+    /// Quotas are set per faucet, so their window is kept here instead of on the shared
+    /// `UserClaimRegistry`: that registry isn't written for unsigned claims at faucets
+    /// without registry approval, and claims at other faucets would push this faucet's
+    /// entries out of a shared buffer. Adds 129 bytes to every `UserFaucetState`.
+    pub recent_claims: [RecentClaim; RECENT_CLAIMS_LEN],
+    pub recent_claims_head: u8,
+    pub flag_count: u8,
+    pub reserved: [u8; 7],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct RecentClaim {
+    pub timestamp: i64,
+    pub amount: u64,
+}
+
+impl UserFaucetState {
+    pub const VERSION: u8 = 1;
+
+    /// Number of claims and summed amount recorded within `window_seconds` before `now`.
+    pub fn window_usage(&self, now: i64, window_seconds: i64) -> (u8, u64) {
+        self.recent_claims
+            .iter()
+            .filter(|entry| {
+                entry.timestamp > 0 && now.saturating_sub(entry.timestamp) < window_seconds
+            })
+            .fold((0, 0), |(count, amount), entry| {
+                (count + 1, amount.saturating_add(entry.amount))
+            })
+    }
+
+    /// When a window capped at `max_claims` next has room, or `None` if it has room now.
+    pub fn window_frees_at(&self, now: i64, window_seconds: i64, max_claims: u8) -> Option<i64> {
+        let mut timestamps: Vec<i64> = self
+            .recent_claims
+            .iter()
+            .map(|entry| entry.timestamp)
+            .filter(|&timestamp| timestamp > 0 && now.saturating_sub(timestamp) < window_seconds)
+            .collect();
+        timestamps.sort_unstable();
+
+        let oldest_to_expire = timestamps.len().checked_sub(max_claims as usize)?;
+
+        timestamps
+            .get(oldest_to_expire)
+            .map(|timestamp| timestamp.saturating_add(window_seconds))
+    }
+
+    /// Overwrites the oldest slot of the ring buffer.
+    pub fn push_recent_claim(&mut self, timestamp: i64, amount: u64) {
+        let head = self.recent_claims_head as usize % RECENT_CLAIMS_LEN;
+
+        self.recent_claims[head] = RecentClaim { timestamp, amount };
+        self.recent_claims_head = ((head + 1) % RECENT_CLAIMS_LEN) as u8;
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Group of wallets a faucet believes belong to one actor (e.g. all funded from the same
+/// source), at `["cluster", faucet_registry, cluster_id]`. Members share the faucet cooldown.
+///
+/// Clusters are scoped to one faucet on purpose: they are linked by that faucet's operators
+/// and attesters, so a faucet can't put wallets on cooldown at faucets that never linked
+/// them. Closed when its last member is unlinked.
+#[account]
+#[derive(InitSpace)]
+pub struct WalletCluster {
+    pub version: u8,
+    pub faucet: Pubkey,
+    pub cluster_id: Pubkey,
+    pub member_count: u32,
+    /// Most recent claim by any member, including claims from before it was linked.
+    pub last_claim_timestamp: Option<i64>,
+    /// Claims recorded by members while linked.
+    pub total_claims: u64,
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 32],
+}
+
+impl WalletCluster {
+    pub const VERSION: u8 = 1;
+}
+
+/// Links a wallet to its cluster at `["cluster_member", faucet_registry, wallet]`; a wallet
+/// belongs to at most one cluster per faucet.
+#[account]
+#[derive(InitSpace)]
+pub struct ClusterMembership {
+    pub version: u8,
+    pub faucet: Pubkey,
+    pub wallet: Pubkey,
+    pub cluster: Pubkey,
+    pub linked_by: Pubkey,
+    pub linked_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+impl ClusterMembership {
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_REASON_LEN: usize = 64;
+
+/// Protocol-wide entries live at `["block", wallet]` and are always `Blocked`;
+/// faucet entries live at `["list", faucet_registry, wallet]`.
+#[account]
+#[derive(InitSpace)]
+pub struct WalletListEntry {
+    pub version: u8,
+    pub wallet: Pubkey,
+    pub faucet: Option<Pubkey>,
+    pub kind: ListKind,
+    #[max_len(MAX_REASON_LEN)]
+    pub reason: String,
+    pub expires_at: Option<i64>,
+    pub added_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ListKind {
+    Blocked,
+    Allowed,
+}
+
+impl WalletListEntry {
+    pub const VERSION: u8 = 1;
+
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+}
@@ -1,16 +1,45 @@
-import { Connection, PublicKey, Keypair, SystemProgram } from '@solana/web3.js';
+import {
+  Connection,
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from '@solana/web3.js';
 import { Program, AnchorProvider, Wallet, BN } from '@coral-xyz/anchor';
 import NodeWallet from '@coral-xyz/anchor/dist/cjs/nodewallet';
 import TapShieldIDL from './idl/tapshield.json';
-import { ClaimRecordInfo, FaucetRegistryStats } from './types';
+import {
+  BatchClaim,
+  BatchMode,
+  ClaimBudget,
+  ClaimerBondInfo,
+  ClaimLogEntryInfo,
+  ClaimQuota,
+  ClaimRecordInfo,
+  EligibilityInfo,
+  FaucetListing,
+  FaucetMetadata,
+  FaucetRegistryStats,
+  ReputationFlag,
+  ReputationPolicy,
+  UserFaucetStateInfo,
+} from './types';
 
 export class TapShield {
   private program: Program;
   private provider: AnchorProvider;
   private faucetKeypair: Keypair;
+  private faucetId: PublicKey;
 
-  constructor(faucetKeypair: Keypair, rpcUrl: string, programId?: PublicKey) {
+  /**
+   * @param faucetKeypair - Owner or recorder key of the faucet
+   * @param faucetId - Stable faucet id, defaults to `faucetKeypair` (the id used when registering)
+   */
+  constructor(faucetKeypair: Keypair, rpcUrl: string, programId?: PublicKey, faucetId?: PublicKey) {
     this.faucetKeypair = faucetKeypair;
+    this.faucetId = faucetId ?? faucetKeypair.publicKey;
 
     const connection = new Connection(rpcUrl, 'confirmed');
     const wallet = new NodeWallet(faucetKeypair);
@@ -32,19 +61,20 @@ export class TapShield {
   /**
    * Register a new faucet in the TapShield Network
    * @param name - Name of the faucet should be of at max 32 chars
+   * @param cooldownSeconds - Cooldown period in seconds enforced on every claim
    * @returns PDA address of the created FaucetRegistry
    */
 
-  async registerFaucet(name: string): Promise<string> {
+  async registerFaucet(name: string, cooldownSeconds: number): Promise<string> {
     const [faucetRegistry] = PublicKey.findProgramAddressSync(
-      [Buffer.from('faucet'), this.faucetKeypair.publicKey.toBuffer()],
+      [Buffer.from('faucet'), this.faucetId.toBuffer()],
       this.program.programId
     );
 
     await this.program.methods
-      .initializeFaucet(name)
+      .initializeFaucet(this.faucetId, name, new BN(cooldownSeconds))
       .accounts({
-        operator: this.faucetKeypair.publicKey,
+        owner: this.faucetKeypair.publicKey,
         faucetRegistry,
         systemProgram: SystemProgram.programId,
       })
@@ -59,22 +89,23 @@ export class TapShield {
    * Record a claim including on-chain cooldown check
    * @param claimerPubkey - Wallet of the claiming user
    * @param amount - Amount in lamports
-   * @param cooldownSeconds - Cooldown period in seconds
-   * @returns PDA address of the created ClaimRecord
+   * @param attestationIx - ed25519 attestation instruction, required when the faucet has attesters
+   * @returns PDA address of the created ClaimRecord, or the faucet's claim log if it has one
    */
 
   async recordClaim(
     claimerPubkey: PublicKey,
     amount: number,
-    cooldownSeconds: number
+    attestationIx?: TransactionInstruction
   ): Promise<string> {
     const [faucetRegistry] = PublicKey.findProgramAddressSync(
-      [Buffer.from('faucet'), this.faucetKeypair.publicKey.toBuffer()],
+      [Buffer.from('faucet'), this.faucetId.toBuffer()],
       this.program.programId
     );
 
     const faucetAccount = await (this.program.account as any).faucetRegistry.fetch(faucetRegistry);
     const claimIndex = faucetAccount.totalClaims;
+    const claimLog: PublicKey | null = faucetAccount.claimLog;
 
     const [claimRecord] = PublicKey.findProgramAddressSync(
       [
@@ -101,6 +132,11 @@ export class TapShield {
     //   lastClaimRecord = lastClaim;
     // }
 
+    const [protocolConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('config')],
+      this.program.programId
+    );
+
     const [userClaimRegistry] = PublicKey.findProgramAddressSync(
       [Buffer.from("user_registry"),
       claimerPubkey.toBuffer()
@@ -110,20 +146,32 @@ export class TapShield {
 
     try {
       await this.program.methods
-        .recordClaim(claimerPubkey, new BN(amount), new BN(cooldownSeconds))
+        .recordClaim(claimerPubkey, new BN(amount))
         .accounts({
           operator: this.faucetKeypair.publicKey,
           claimer: claimerPubkey,
           faucetRegistry,
-          claimRecord,
+          protocolConfig,
+          claimRecord: claimLog ? null : claimRecord,
           userClaimRegistry,
+          userFaucetState: this.getUserFaucetStatePDA(claimerPubkey),
+          protocolBlockEntry: this.getProtocolBlockEntryPDA(claimerPubkey),
+          faucetListEntry: this.getFaucetListEntryPDA(claimerPubkey),
+          claimerBond: this.getClaimerBondPDA(claimerPubkey),
+          clusterMembership: this.getClusterMembershipPDA(claimerPubkey),
+          walletCluster: await this.getWalletClusterOf(claimerPubkey),
+          instructionSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           systemProgram: SystemProgram.programId,
+          claimLog,
         })
+        .preInstructions(attestationIx ? [attestationIx] : [])
         .rpc();
 
-      console.log(`Claim recorded: ${claimRecord.toBase58()}`);
+      const recordedIn = claimLog ?? claimRecord;
 
-      return claimRecord.toBase58();
+      console.log(`Claim recorded: ${recordedIn.toBase58()}`);
+
+      return recordedIn.toBase58();
     } catch (err: any) {
       if (
         err.message?.includes('ClaimTooRecent') ||
@@ -131,10 +179,278 @@ export class TapShield {
       ) {
         throw new Error('COOLDOWN_ACTIVE: User claimed too recently!');
       }
+      if (
+        err.message?.includes('GlobalCooldownActive') ||
+        err.logs?.some((log: string) => log.includes('GlobalCooldownActive'))
+      ) {
+        throw new Error('COOLDOWN_ACTIVE: User claimed from another faucet too recently!');
+      }
       throw err;
     }
   }
 
+  /**
+   * Update the cooldown policy stored on the faucet registry
+   * @param cooldownSeconds - New cooldown period in seconds
+   * @param quota - Optional rolling-window quota, omit to disable
+   * @param requireClaimerSignature - Reject claims the claimer did not sign
+   */
+
+  async updateFaucetPolicy(
+    cooldownSeconds: number,
+    quota?: ClaimQuota,
+    requireClaimerSignature = false
+  ): Promise<void> {
+    const [faucetRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('faucet'), this.faucetId.toBuffer()],
+      this.program.programId
+    );
+
+    await this.program.methods
+      .updateFaucetPolicy(
+        new BN(cooldownSeconds),
+        quota
+          ? {
+              maxClaims: quota.maxClaims,
+              maxAmount: new BN(quota.maxAmount),
+              windowSeconds: new BN(quota.windowSeconds),
+            }
+          : null,
+        requireClaimerSignature
+      )
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry,
+      })
+      .rpc();
+  }
+
+  /**
+   * Build a claim transaction the claimer has to sign, co-signed by the operator as fee payer
+   * @param claimerPubkey - Wallet of the claiming user
+   * @param amount - Amount in lamports
+   * @param attestationIx - ed25519 attestation instruction, required when the faucet has attesters
+   * @returns Operator-signed transaction waiting for the claimer's signature
+   */
+
+  async buildSignedClaimTransaction(
+    claimerPubkey: PublicKey,
+    amount: number,
+    attestationIx?: TransactionInstruction
+  ): Promise<Transaction> {
+    const faucetRegistry = this.getFaucetRegistryPDA();
+
+    const faucetAccount = await (this.program.account as any).faucetRegistry.fetch(faucetRegistry);
+
+    const [claimRecord] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('claim'),
+        claimerPubkey.toBuffer(),
+        faucetRegistry.toBuffer(),
+        faucetAccount.totalClaims.toArrayLike(Buffer, 'le', 8),
+      ],
+      this.program.programId
+    );
+
+    const [protocolConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('config')],
+      this.program.programId
+    );
+
+    const [userClaimRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_registry'), claimerPubkey.toBuffer()],
+      this.program.programId
+    );
+
+    const instruction = await this.program.methods
+      .recordClaim(claimerPubkey, new BN(amount))
+      .accounts({
+        operator: this.faucetKeypair.publicKey,
+        claimer: claimerPubkey,
+        faucetRegistry,
+        protocolConfig,
+        claimRecord: faucetAccount.claimLog ? null : claimRecord,
+        userClaimRegistry,
+        userFaucetState: this.getUserFaucetStatePDA(claimerPubkey),
+        protocolBlockEntry: this.getProtocolBlockEntryPDA(claimerPubkey),
+        faucetListEntry: this.getFaucetListEntryPDA(claimerPubkey),
+        claimerBond: this.getClaimerBondPDA(claimerPubkey),
+        clusterMembership: this.getClusterMembershipPDA(claimerPubkey),
+        walletCluster: await this.getWalletClusterOf(claimerPubkey),
+        instructionSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        claimLog: faucetAccount.claimLog,
+      })
+      .instruction();
+
+    // Co-signed claims update the global user registry even at unapproved faucets
+    instruction.keys.find((meta) => meta.pubkey.equals(claimerPubkey))!.isSigner = true;
+
+    const tx = new Transaction().add(...(attestationIx ? [attestationIx] : []), instruction);
+
+    tx.feePayer = this.faucetKeypair.publicKey;
+    tx.recentBlockhash = (await this.provider.connection.getLatestBlockhash()).blockhash;
+    tx.partialSign(this.faucetKeypair);
+
+    return tx;
+  }
+
+  /**
+   * Deposit lamports into the faucet vault used by `claim`
+   * @param amount - Amount in lamports
+   */
+
+  async depositVault(amount: number): Promise<void> {
+    await this.program.methods
+      .depositVault(new BN(amount))
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        vault: this.getVaultPDA(),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Withdraw lamports from the faucet vault back to the owner
+   * @param amount - Amount in lamports
+   */
+
+  async withdrawVault(amount: number): Promise<void> {
+    await this.program.methods
+      .withdrawVault(new BN(amount))
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        vault: this.getVaultPDA(),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Check cooldown, record the claim and pay the claimer from the faucet vault in one transaction
+   * @param claimerPubkey - Wallet of the claiming user
+   * @param amount - Amount in lamports
+   * @param attestationIx - ed25519 attestation instruction, required when the faucet has attesters
+   * @returns PDA address of the created ClaimRecord, or the faucet's claim log if it has one
+   */
+
+  async claim(
+    claimerPubkey: PublicKey,
+    amount: number,
+    attestationIx?: TransactionInstruction
+  ): Promise<string> {
+    const faucetRegistry = this.getFaucetRegistryPDA();
+
+    const faucetAccount = await (this.program.account as any).faucetRegistry.fetch(faucetRegistry);
+
+    const [claimRecord] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('claim'),
+        claimerPubkey.toBuffer(),
+        faucetRegistry.toBuffer(),
+        faucetAccount.totalClaims.toArrayLike(Buffer, 'le', 8),
+      ],
+      this.program.programId
+    );
+
+    const [protocolConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('config')],
+      this.program.programId
+    );
+
+    const [userClaimRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_registry'), claimerPubkey.toBuffer()],
+      this.program.programId
+    );
+
+    await this.program.methods
+      .claim(new BN(amount))
+      .accounts({
+        operator: this.faucetKeypair.publicKey,
+        claimer: claimerPubkey,
+        faucetRegistry,
+        protocolConfig,
+        vault: this.getVaultPDA(),
+        claimRecord: faucetAccount.claimLog ? null : claimRecord,
+        userClaimRegistry,
+        userFaucetState: this.getUserFaucetStatePDA(claimerPubkey),
+        protocolBlockEntry: this.getProtocolBlockEntryPDA(claimerPubkey),
+        faucetListEntry: this.getFaucetListEntryPDA(claimerPubkey),
+        claimerBond: this.getClaimerBondPDA(claimerPubkey),
+        clusterMembership: this.getClusterMembershipPDA(claimerPubkey),
+        walletCluster: await this.getWalletClusterOf(claimerPubkey),
+        instructionSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        claimLog: faucetAccount.claimLog,
+      })
+      .preInstructions(attestationIx ? [attestationIx] : [])
+      .rpc();
+
+    return (faucetAccount.claimLog ?? claimRecord).toBase58();
+  }
+
+  /**
+   * Record several claims in one transaction. Batched claims create no ClaimRecord, so they
+   * don't show up in getClaimHistory, only in the claim log if the faucet has one. Not available to faucets with attesters or claimer signatures.
+   * @param claims - Claimers and amounts in lamports; each takes 7 accounts, so keep batches small
+   * @param mode - 'atomic' fails the whole batch on the first ineligible claim, 'skipIneligible'
+   *   records the rest and reports skipped ones as ClaimRejected events
+   * @returns Transaction signature
+   */
+
+  async recordClaimsBatch(claims: BatchClaim[], mode: BatchMode = 'atomic'): Promise<string> {
+    const remainingAccounts = [];
+
+    for (const { claimer } of claims) {
+      const [userClaimRegistry] = PublicKey.findProgramAddressSync(
+        [Buffer.from('user_registry'), claimer.toBuffer()],
+        this.program.programId
+      );
+      const walletCluster = await this.getWalletClusterOf(claimer);
+
+      remainingAccounts.push(
+        { pubkey: userClaimRegistry, isSigner: false, isWritable: true },
+        { pubkey: this.getUserFaucetStatePDA(claimer), isSigner: false, isWritable: true },
+        { pubkey: this.getProtocolBlockEntryPDA(claimer), isSigner: false, isWritable: false },
+        { pubkey: this.getFaucetListEntryPDA(claimer), isSigner: false, isWritable: false },
+        { pubkey: this.getClaimerBondPDA(claimer), isSigner: false, isWritable: false },
+        { pubkey: this.getClusterMembershipPDA(claimer), isSigner: false, isWritable: false },
+        {
+          pubkey: walletCluster ?? this.program.programId,
+          isSigner: false,
+          isWritable: walletCluster !== null,
+        }
+      );
+    }
+
+    const [protocolConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('config')],
+      this.program.programId
+    );
+
+    const faucetAccount = await (this.program.account as any).faucetRegistry.fetch(
+      this.getFaucetRegistryPDA()
+    );
+
+    return await this.program.methods
+      .recordClaimsBatch(
+        claims.map(({ claimer, amount }) => ({ claimer, amount: new BN(amount) })),
+        { [mode]: {} }
+      )
+      .accounts({
+        operator: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        protocolConfig,
+        systemProgram: SystemProgram.programId,
+        claimLog: faucetAccount.claimLog,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+  }
+
   /**
    * get claim history for a wallet
    * @param claimerPubkey - Wallet to query
@@ -145,7 +461,8 @@ export class TapShield {
     const claimRecords = await (this.program.account as any).claimRecord.all([
       {
         memcmp: {
-          offset: 8,
+          // discriminator + version byte
+          offset: 9,
           bytes: claimerPubkey.toBase58(),
         },
       },
@@ -160,44 +477,832 @@ export class TapShield {
   }
 
   /**
-   * Getting faucet stats
-   * @returns faucet stats including total claims
+   * Allocate a claim log and attach it to the faucet (owner only). Claims are then appended to
+   * the log instead of creating a ClaimRecord each, so they cost no rent
+   * @returns Address of the claim log
    */
 
-  async getFaucetStats(): Promise<FaucetRegistryStats> {
-    const [faucetRegistry] = PublicKey.findProgramAddressSync(
-      [Buffer.from('faucet'), this.faucetKeypair.publicKey.toBuffer()],
-      this.program.programId
-    );
+  async createClaimLog(): Promise<string> {
+    const claimLog = Keypair.generate();
 
-    const faucet = await (this.program.account as any).faucetRegistry.fetch(
-      faucetRegistry
-    );
+    await this.program.methods
+      .initClaimLog()
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        claimLog: claimLog.publicKey,
+      })
+      .preInstructions([
+        await (this.program.account as any).claimLog.createInstruction(claimLog),
+      ])
+      .signers([claimLog])
+      .rpc();
 
-    return {
-      operator: faucet.operator.toBase58(),
-      name: faucet.name,
-      totalClaims: faucet.totalClaims.toNumber(),
-      createdAt: faucet.createdAt.toNumber(),
-    };
+    return claimLog.publicKey.toBase58();
   }
 
   /**
-   * getting program instance
+   * Detach and close the faucet's claim log, reclaiming its rent (owner only). Later claims
+   * create ClaimRecords again
    */
-  getProgram(): Program {
-    return this.program;
+
+  async closeClaimLog(): Promise<void> {
+    const faucetRegistry = this.getFaucetRegistryPDA();
+    const faucetAccount = await (this.program.account as any).faucetRegistry.fetch(faucetRegistry);
+
+    await this.program.methods
+      .closeClaimLog()
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry,
+        claimLog: faucetAccount.claimLog,
+      })
+      .rpc();
   }
 
   /**
-   * getting faucet registry PDA
+   * Getting the most recent claims from the faucet's claim log
+   * @returns entries oldest first, or null if the faucet has no claim log
    */
-  getFaucetRegistryPDA(): PublicKey {
-    const [faucetRegistry] = PublicKey.findProgramAddressSync(
-      [Buffer.from('faucet'), this.faucetKeypair.publicKey.toBuffer()],
-      // SystemProgram.programId
-      this.program.programId
+
+  async getClaimLog(): Promise<ClaimLogEntryInfo[] | null> {
+    const faucetAccount = await (this.program.account as any).faucetRegistry.fetch(
+      this.getFaucetRegistryPDA()
     );
-    return faucetRegistry;
+
+    if (!faucetAccount.claimLog) {
+      return null;
+    }
+
+    const log = await (this.program.account as any).claimLog.fetch(faucetAccount.claimLog);
+    const capacity = log.entries.length;
+    const total = log.totalEntries.toNumber();
+    const len = Math.min(total, capacity);
+
+    return Array.from({ length: len }, (_, offset) => {
+      const entry = log.entries[(total - len + offset) % capacity];
+
+      return {
+        claimer: entry.claimer.toBase58(),
+        amount: entry.amount.toNumber(),
+        timestamp: entry.timestamp.toNumber(),
+      };
+    });
+  }
+
+  /**
+   * Close expired claim records of a wallet and reclaim their rent (owner only)
+   * @param claimerPubkey - Wallet whose records to close
+   * @param claimRecords - Claim record addresses; must be older than the faucet's longest cooldown or quota window
+   */
+
+  async closeClaimRecords(
+    claimerPubkey: PublicKey,
+    claimRecords: PublicKey[]
+  ): Promise<void> {
+    await this.program.methods
+      .closeClaimRecords()
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        claimer: claimerPubkey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        userClaimRegistry: PublicKey.findProgramAddressSync(
+          [Buffer.from('user_registry'), claimerPubkey.toBuffer()],
+          this.program.programId
+        )[0],
+      })
+      .remainingAccounts(
+        claimRecords.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      )
+      .rpc();
+  }
+
+  /**
+   * Allow another key to record claims for this faucet (owner only)
+   * @param recorder - Recorder public key
+   */
+
+  async addRecorder(recorder: PublicKey): Promise<void> {
+    await this.program.methods
+      .addRecorder(recorder)
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Revoke a recorder key (owner only)
+   * @param recorder - Recorder public key
+   */
+
+  async removeRecorder(recorder: PublicKey): Promise<void> {
+    await this.program.methods
+      .removeRecorder(recorder)
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Replace a recorder key in one step (owner only)
+   * @param oldRecorder - Recorder being rotated out
+   * @param newRecorder - Recorder being rotated in
+   */
+
+  async rotateRecorder(oldRecorder: PublicKey, newRecorder: PublicKey): Promise<void> {
+    await this.program.methods
+      .rotateRecorder(oldRecorder, newRecorder)
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Start a two-step ownership handoff, pass null to cancel (owner only)
+   * @param newOwner - Key that has to call `acceptFaucetOwnership`
+   */
+
+  async transferFaucetOwnership(newOwner: PublicKey | null): Promise<void> {
+    await this.program.methods
+      .transferFaucetOwnership(newOwner)
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Accept a pending ownership handoff with this instance's keypair
+   */
+
+  async acceptFaucetOwnership(): Promise<void> {
+    await this.program.methods
+      .acceptFaucetOwnership()
+      .accounts({
+        newOwner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Block or allowlist a wallet for this faucet (owner only)
+   * @param wallet - Wallet to list
+   * @param kind - 'blocked' or 'allowed'
+   * @param reason - Free-form reason, max 64 chars
+   * @param expiresAt - Optional unix timestamp after which the entry is ignored
+   */
+
+  async setFaucetListEntry(
+    wallet: PublicKey,
+    kind: 'blocked' | 'allowed',
+    reason: string,
+    expiresAt?: number
+  ): Promise<void> {
+    await this.program.methods
+      .setFaucetListEntry(
+        wallet,
+        kind === 'blocked' ? { blocked: {} } : { allowed: {} },
+        reason,
+        expiresAt !== undefined ? new BN(expiresAt) : null
+      )
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        listEntry: this.getFaucetListEntryPDA(wallet),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Remove a wallet's block/allowlist entry for this faucet (owner only)
+   * @param wallet - Listed wallet
+   */
+
+  async removeFaucetListEntry(wallet: PublicKey): Promise<void> {
+    await this.program.methods
+      .removeFaucetListEntry()
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        listEntry: this.getFaucetListEntryPDA(wallet),
+      })
+      .rpc();
+  }
+
+  /**
+   * Cap the total amount disbursed per period (owner only)
+   * @param budget - Budget per period, omit to remove the cap
+   */
+
+  async setClaimBudget(budget?: ClaimBudget): Promise<void> {
+    await this.program.methods
+      .setClaimBudget(
+        budget
+          ? {
+              maxAmount: new BN(budget.maxAmount),
+              period:
+                budget.period === 'epoch'
+                  ? { epoch: {} }
+                  : { seconds: [new BN(budget.period)] },
+            }
+          : null
+      )
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Require claimers to keep a refundable SOL bond before claiming (owner only)
+   * @param lamports - Minimum bond, 0 to turn bonded mode off
+   */
+
+  async setRequiredBond(lamports: number): Promise<void> {
+    await this.program.methods
+      .setRequiredBond(new BN(lamports))
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Build a transaction that bonds SOL for a claimer; the claimer signs and pays
+   * @param claimerPubkey - Wallet of the claimer
+   * @param amount - Amount in lamports
+   */
+
+  async buildDepositBondTransaction(claimerPubkey: PublicKey, amount: number): Promise<Transaction> {
+    const tx = await this.program.methods
+      .depositBond(new BN(amount))
+      .accounts({
+        claimer: claimerPubkey,
+        claimerBond: this.getClaimerBondPDA(claimerPubkey),
+        systemProgram: SystemProgram.programId,
+      })
+      .transaction();
+
+    tx.feePayer = claimerPubkey;
+    tx.recentBlockhash = (await this.provider.connection.getLatestBlockhash()).blockhash;
+
+    return tx;
+  }
+
+  /**
+   * Build a transaction that returns bonded SOL to the claimer; withdrawing everything closes the bond
+   * @param claimerPubkey - Wallet of the claimer
+   * @param amount - Amount in lamports
+   */
+
+  async buildWithdrawBondTransaction(claimerPubkey: PublicKey, amount: number): Promise<Transaction> {
+    const [userClaimRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_registry'), claimerPubkey.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .withdrawBond(new BN(amount))
+      .accounts({
+        claimer: claimerPubkey,
+        claimerBond: this.getClaimerBondPDA(claimerPubkey),
+        userClaimRegistry,
+      })
+      .transaction();
+
+    tx.feePayer = claimerPubkey;
+    tx.recentBlockhash = (await this.provider.connection.getLatestBlockhash()).blockhash;
+
+    return tx;
+  }
+
+  /**
+   * Ask the protocol admin to slash a claimer's bond into this faucet's vault (operator).
+   * Only bonded faucets can report, and only wallets that claimed at this faucet.
+   * @param claimerPubkey - Abusive claimer
+   * @param amount - Lamports to slash, capped at the bond when approved
+   * @param reason - Up to 64 characters
+   */
+
+  async reportAbuse(claimerPubkey: PublicKey, amount: number, reason: string): Promise<void> {
+    await this.program.methods
+      .reportAbuse(new BN(amount), reason)
+      .accounts({
+        operator: this.faucetKeypair.publicKey,
+        claimer: claimerPubkey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        userFaucetState: this.getUserFaucetStatePDA(claimerPubkey),
+        claimerBond: this.getClaimerBondPDA(claimerPubkey),
+        slashReport: this.getSlashReportPDA(claimerPubkey),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Getting a claimer's bond
+   * @returns null if the claimer has no bond
+   */
+
+  async getClaimerBond(claimerPubkey: PublicKey): Promise<ClaimerBondInfo | null> {
+    const bond = await (this.program.account as any).claimerBond.fetchNullable(
+      this.getClaimerBondPDA(claimerPubkey)
+    );
+
+    if (!bond) {
+      return null;
+    }
+
+    return {
+      amount: bond.amount.toNumber(),
+      pendingReports: bond.pendingReports,
+      depositedAt: bond.depositedAt.toNumber(),
+    };
+  }
+
+  /**
+   * Link a wallet into a cluster of wallets controlled by one actor (operator or attester).
+   * Every wallet in a cluster waits out the faucet cooldown since the cluster's latest claim.
+   * @param clusterId - Any stable key for the group, e.g. the wallet that funded them
+   * @param wallet - Wallet to link; a wallet belongs to at most one cluster per faucet
+   */
+
+  async linkWallet(clusterId: PublicKey, wallet: PublicKey): Promise<void> {
+    await this.program.methods
+      .linkWallet(clusterId, wallet)
+      .accounts({
+        authority: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        walletCluster: this.getWalletClusterPDA(clusterId),
+        clusterMembership: this.getClusterMembershipPDA(wallet),
+        userFaucetState: this.getUserFaucetStatePDA(wallet),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Remove a wallet from its cluster (operator or attester)
+   * @param wallet - Linked wallet
+   */
+
+  async unlinkWallet(wallet: PublicKey): Promise<void> {
+    const clusterMembership = this.getClusterMembershipPDA(wallet);
+    const membership = await (this.program.account as any).clusterMembership.fetch(
+      clusterMembership
+    );
+
+    await this.program.methods
+      .unlinkWallet()
+      .accounts({
+        authority: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        clusterMembership,
+        walletCluster: membership.cluster,
+      })
+      .rpc();
+  }
+
+  /**
+   * Gate claims on the claimer's reputation score (owner only)
+   * @param policy - Minimum score and score-scaled max amount, omit to remove the gate
+   */
+
+  async setReputationPolicy(policy?: ReputationPolicy): Promise<void> {
+    await this.program.methods
+      .setReputationPolicy(
+        policy
+          ? {
+              minScore: policy.minScore,
+              maxAmount: new BN(policy.maxAmount),
+            }
+          : null
+      )
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Lower a claimer's reputation after a rejected claim or abuse (operator)
+   * @param claimerPubkey - Flagged claimer
+   * @param flag - 'rejectedAttempt' (-25) or 'abuse' (-100)
+   */
+
+  async flagClaimer(claimerPubkey: PublicKey, flag: ReputationFlag): Promise<void> {
+    const [userClaimRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_registry'), claimerPubkey.toBuffer()],
+      this.program.programId
+    );
+
+    await this.program.methods
+      .flagClaimer({ [flag]: {} })
+      .accounts({
+        operator: this.faucetKeypair.publicKey,
+        claimer: claimerPubkey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        userFaucetState: this.getUserFaucetStatePDA(claimerPubkey),
+        userClaimRegistry,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Getting a claimer's reputation score
+   * @returns score between 0 and 1000; wallets never rewarded or flagged have 500
+   */
+
+  async getReputation(claimerPubkey: PublicKey): Promise<number> {
+    const [userClaimRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_registry'), claimerPubkey.toBuffer()],
+      this.program.programId
+    );
+
+    const registry = await (this.program.account as any).userClaimRegistry.fetchNullable(
+      userClaimRegistry
+    );
+
+    return registry?.reputationScore ?? 500;
+  }
+
+  /**
+   * Let an on-chain program record claims for this faucet through `check_and_record` (owner only)
+   * @param programId - Calling program, or null to unbind
+   */
+
+  async bindFaucetProgram(programId: PublicKey | null): Promise<void> {
+    await this.program.methods
+      .bindFaucetProgram(programId)
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Update the faucet's directory metadata (owner only)
+   * @param metadata - Fields to change; omitted fields keep their current value
+   */
+
+  async updateFaucetMetadata(metadata: FaucetMetadata): Promise<void> {
+    await this.program.methods
+      .updateFaucetMetadata(
+        metadata.name ?? null,
+        metadata.uri ?? null,
+        metadata.category ?? null,
+        metadata.contact ?? null
+      )
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * List every registered faucet with its directory metadata
+   */
+
+  async listFaucets(): Promise<FaucetListing[]> {
+    const faucets = await (this.program.account as any).faucetRegistry.all();
+
+    return faucets.map((faucet: any) => ({
+      faucet: faucet.publicKey.toBase58(),
+      name: faucet.account.name,
+      uri: faucet.account.uri,
+      category: faucet.account.category,
+      contact: faucet.account.contact,
+      paused: faucet.account.paused,
+    }));
+  }
+
+  /**
+   * Upgrade a faucet registry created before account versioning (owner only)
+   * @param cooldownSeconds - Cooldown to store, legacy registries did not keep one
+   */
+
+  async migrateFaucetRegistry(cooldownSeconds: number): Promise<void> {
+    await this.program.methods
+      .migrateFaucetRegistry(new BN(cooldownSeconds))
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Stop accepting claims until resumed (owner only)
+   */
+
+  async pauseFaucet(): Promise<void> {
+    await this.program.methods
+      .pauseFaucet()
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Accept claims again after a pause (owner only)
+   */
+
+  async resumeFaucet(): Promise<void> {
+    await this.program.methods
+      .resumeFaucet()
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Close the faucet registry and reclaim its rent; the vault must be empty (owner only)
+   * @param vaultAta - Token faucets only: the vault's token account, closed as well
+   * @param tokenProgram - Token faucets only: token program owning the mint
+   */
+
+  async closeFaucet(vaultAta?: PublicKey, tokenProgram?: PublicKey): Promise<void> {
+    await this.program.methods
+      .closeFaucet()
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        vault: this.getVaultPDA(),
+        vaultAta: vaultAta ?? null,
+        tokenProgram: tokenProgram ?? null,
+      })
+      .rpc();
+  }
+
+  /**
+   * Only accept claims from allowlisted wallets (owner only)
+   * @param allowlistOnly - Turn allowlist mode on or off
+   */
+
+  async setAllowlistOnly(allowlistOnly: boolean): Promise<void> {
+    await this.program.methods
+      .setAllowlistOnly(allowlistOnly)
+      .accounts({
+        owner: this.faucetKeypair.publicKey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+      })
+      .rpc();
+  }
+
+  /**
+   * Getting a wallet's claim state at this faucet
+   * @param claimerPubkey - Wallet to query
+   * @returns null if the wallet never claimed here
+   */
+
+  async getUserFaucetState(claimerPubkey: PublicKey): Promise<UserFaucetStateInfo | null> {
+    const state = await (this.program.account as any).userFaucetState.fetchNullable(
+      this.getUserFaucetStatePDA(claimerPubkey)
+    );
+
+    if (!state) {
+      return null;
+    }
+
+    return {
+      firstClaimTimestamp: state.firstClaimTimestamp.toNumber(),
+      lastClaimTimestamp: state.lastClaimTimestamp.toNumber(),
+      claimCount: state.claimCount.toNumber(),
+      totalAmount: state.totalAmount.toNumber(),
+    };
+  }
+
+  /**
+   * Dry-run the claim checks for a wallet, e.g. to show "come back in 3h". Nothing is written or paid.
+   * Attestations and claimer signatures are not part of the answer.
+   * @param claimerPubkey - Wallet to check
+   * @param amount - Amount the wallet would claim, in lamports
+   */
+
+  async checkEligibility(claimerPubkey: PublicKey, amount: number): Promise<EligibilityInfo> {
+    const [protocolConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('config')],
+      this.program.programId
+    );
+
+    const [userClaimRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_registry'), claimerPubkey.toBuffer()],
+      this.program.programId
+    );
+
+    const eligibility = await this.program.methods
+      .checkEligibility(new BN(amount))
+      .accounts({
+        claimer: claimerPubkey,
+        faucetRegistry: this.getFaucetRegistryPDA(),
+        protocolConfig,
+        userClaimRegistry,
+        userFaucetState: this.getUserFaucetStatePDA(claimerPubkey),
+        protocolBlockEntry: this.getProtocolBlockEntryPDA(claimerPubkey),
+        faucetListEntry: this.getFaucetListEntryPDA(claimerPubkey),
+        claimerBond: this.getClaimerBondPDA(claimerPubkey),
+        clusterMembership: this.getClusterMembershipPDA(claimerPubkey),
+        walletCluster: await this.getWalletClusterOf(claimerPubkey),
+      })
+      .view();
+
+    return {
+      eligible: eligibility.eligible,
+      errorCode: eligibility.errorCode,
+      secondsRemaining: eligibility.secondsRemaining.toNumber(),
+      remainingClaims: eligibility.remainingClaims,
+      remainingAmount: eligibility.remainingAmount?.toNumber() ?? null,
+      remainingBudget: eligibility.remainingBudget?.toNumber() ?? null,
+      reputation: eligibility.reputation,
+    };
+  }
+
+  /**
+   * Getting faucet stats
+   * @returns faucet stats including total claims
+   */
+
+  async getFaucetStats(): Promise<FaucetRegistryStats> {
+    const [faucetRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('faucet'), this.faucetId.toBuffer()],
+      this.program.programId
+    );
+
+    const faucet = await (this.program.account as any).faucetRegistry.fetch(
+      faucetRegistry
+    );
+
+    return {
+      owner: faucet.owner.toBase58(),
+      recorders: faucet.recorders.map((recorder: PublicKey) => recorder.toBase58()),
+      name: faucet.name,
+      totalClaims: faucet.totalClaims.toNumber(),
+      closedClaims: faucet.closedClaims.toNumber(),
+      closedAmount: faucet.closedAmount.toNumber(),
+      totalAmount: faucet.totalAmount.toNumber(),
+      uniqueClaimers: faucet.uniqueClaimers.toNumber(),
+      createdAt: faucet.createdAt.toNumber(),
+      cooldownSeconds: faucet.cooldownSeconds.toNumber(),
+      claimBudget: faucet.claimBudget
+        ? {
+            maxAmount: faucet.claimBudget.maxAmount.toNumber(),
+            period: faucet.claimBudget.period.epoch
+              ? 'epoch'
+              : faucet.claimBudget.period.seconds[0].toNumber(),
+          }
+        : null,
+      budgetSpent: faucet.budgetSpent.toNumber(),
+      uri: faucet.uri,
+      category: faucet.category,
+      contact: faucet.contact,
+      paused: faucet.paused,
+      requiredBond: faucet.requiredBond.toNumber(),
+      reputationPolicy: faucet.reputationPolicy
+        ? {
+            minScore: faucet.reputationPolicy.minScore,
+            maxAmount: faucet.reputationPolicy.maxAmount.toNumber(),
+          }
+        : null,
+      reputationApproved: faucet.reputationApproved,
+      registryApproved: faucet.registryApproved,
+      claimLog: faucet.claimLog ? faucet.claimLog.toBase58() : null,
+    };
+  }
+
+  /**
+   * getting program instance
+   */
+  getProgram(): Program {
+    return this.program;
+  }
+
+  /**
+   * getting faucet registry PDA
+   */
+  getFaucetRegistryPDA(): PublicKey {
+    const [faucetRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('faucet'), this.faucetId.toBuffer()],
+      this.program.programId
+    );
+    return faucetRegistry;
+  }
+
+  /**
+   * getting faucet vault PDA
+   */
+  getVaultPDA(): PublicKey {
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), this.getFaucetRegistryPDA().toBuffer()],
+      this.program.programId
+    );
+    return vault;
+  }
+
+  /**
+   * getting the per-faucet state PDA of a wallet
+   */
+  getUserFaucetStatePDA(wallet: PublicKey): PublicKey {
+    const [state] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_faucet'), wallet.toBuffer(), this.getFaucetRegistryPDA().toBuffer()],
+      this.program.programId
+    );
+    return state;
+  }
+
+  /**
+   * getting the protocol-wide blocklist entry PDA of a wallet
+   */
+  getProtocolBlockEntryPDA(wallet: PublicKey): PublicKey {
+    const [entry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('block'), wallet.toBuffer()],
+      this.program.programId
+    );
+    return entry;
+  }
+
+  /**
+   * getting this faucet's block/allowlist entry PDA of a wallet
+   */
+  getFaucetListEntryPDA(wallet: PublicKey): PublicKey {
+    const [entry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('list'), this.getFaucetRegistryPDA().toBuffer(), wallet.toBuffer()],
+      this.program.programId
+    );
+    return entry;
+  }
+
+  /**
+   * getting the bond PDA of a claimer
+   */
+  getClaimerBondPDA(wallet: PublicKey): PublicKey {
+    const [bond] = PublicKey.findProgramAddressSync(
+      [Buffer.from('bond'), wallet.toBuffer()],
+      this.program.programId
+    );
+    return bond;
+  }
+
+  /**
+   * getting this faucet's pending slash report PDA against a claimer
+   */
+  getSlashReportPDA(wallet: PublicKey): PublicKey {
+    const [report] = PublicKey.findProgramAddressSync(
+      [Buffer.from('slash'), this.getFaucetRegistryPDA().toBuffer(), wallet.toBuffer()],
+      this.program.programId
+    );
+    return report;
+  }
+
+  /**
+   * getting this faucet's wallet cluster PDA for a cluster id
+   */
+  getWalletClusterPDA(clusterId: PublicKey): PublicKey {
+    const [cluster] = PublicKey.findProgramAddressSync(
+      [Buffer.from('cluster'), this.getFaucetRegistryPDA().toBuffer(), clusterId.toBuffer()],
+      this.program.programId
+    );
+    return cluster;
+  }
+
+  /**
+   * getting this faucet's cluster membership PDA of a wallet
+   */
+  getClusterMembershipPDA(wallet: PublicKey): PublicKey {
+    const [membership] = PublicKey.findProgramAddressSync(
+      [Buffer.from('cluster_member'), this.getFaucetRegistryPDA().toBuffer(), wallet.toBuffer()],
+      this.program.programId
+    );
+    return membership;
+  }
+
+  /**
+   * the cluster a wallet is linked to at this faucet, which claims must pass along
+   */
+  private async getWalletClusterOf(wallet: PublicKey): Promise<PublicKey | null> {
+    const membership = await (this.program.account as any).clusterMembership.fetchNullable(
+      this.getClusterMembershipPDA(wallet)
+    );
+    return membership ? membership.cluster : null;
   }
 }
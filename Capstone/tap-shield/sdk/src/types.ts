@@ -7,13 +7,105 @@ export interface ClaimRecordInfo {
   timestamp: number;
 }
 
+export interface UserFaucetStateInfo {
+  firstClaimTimestamp: number;
+  lastClaimTimestamp: number;
+  claimCount: number;
+  totalAmount: number;
+}
+
+export interface EligibilityInfo {
+  eligible: boolean;
+  // TapShield error code of the first failing check, null when eligible
+  errorCode: number | null;
+  // seconds until the cooldowns and the claim-count quota allow the next claim
+  secondsRemaining: number;
+  remainingClaims: number | null;
+  remainingAmount: number | null;
+  remainingBudget: number | null;
+  reputation: number;
+}
+
 export interface FaucetRegistryStats {
-  operator: string;
+  owner: string;
+  recorders: string[];
   name: string;
   totalClaims: number;
+  closedClaims: number;
+  closedAmount: number;
+  totalAmount: number;
+  uniqueClaimers: number;
   createdAt: number;
+  cooldownSeconds: number;
+  claimBudget: ClaimBudget | null;
+  budgetSpent: number;
+  uri: string;
+  category: string;
+  contact: string;
+  paused: boolean;
+  requiredBond: number;
+  reputationPolicy: ReputationPolicy | null;
+  reputationApproved: boolean;
+  registryApproved: boolean;
+  claimLog: string | null;
+}
+
+export interface ClaimLogEntryInfo {
+  claimer: string;
+  amount: number;
+  timestamp: number;
+}
+
+export interface ClaimerBondInfo {
+  amount: number;
+  pendingReports: number;
+  depositedAt: number;
+}
+
+export interface FaucetMetadata {
+  name?: string;
+  uri?: string;
+  category?: string;
+  contact?: string;
 }
 
+export interface FaucetListing {
+  faucet: string;
+  name: string;
+  uri: string;
+  category: string;
+  contact: string;
+  paused: boolean;
+}
+
+export interface ClaimQuota {
+  maxClaims: number;
+  maxAmount: number;
+  windowSeconds: number;
+}
+
+export interface ClaimBudget {
+  maxAmount: number;
+  // seconds per period (86400 = daily), or 'epoch' for one Solana epoch
+  period: number | 'epoch';
+}
+
+export interface BatchClaim {
+  claimer: PublicKey;
+  amount: number;
+}
+
+export type BatchMode = 'atomic' | 'skipIneligible';
+
+export interface ReputationPolicy {
+  // claimers scoring below this (0-1000) are rejected
+  minScore: number;
+  // largest claim at score 1000, scaled down linearly with the score; 0 disables scaling
+  maxAmount: number;
+}
+
+export type ReputationFlag = 'rejectedAttempt' | 'abuse';
+
 export interface TapShieldConfig {
   programId?: string;
   rpcUrl?: string;
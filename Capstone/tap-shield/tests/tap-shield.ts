@@ -1,7 +1,7 @@
 import * as anchor from "@coral-xyz/anchor"
 import { Program } from "@coral-xyz/anchor"
 import { TapShield } from "../target/types/tap_shield"
-import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js"
+import { BPF_LOADER_UPGRADEABLE_PROGRAM_ID, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js"
 import { expect } from "chai"
 
 describe("tap-shield", () => {
@@ -17,10 +17,12 @@ describe("tap-shield", () => {
   let claimRecordPda: PublicKey
   let userClaimRegistryPda: PublicKey
   let nextClaimPda: PublicKey
+  let protocolConfigPda: PublicKey
 
   const TEST_FAUCET_NAME = "TEST FAUCET"
   const CLAIM_AMOUNT = new anchor.BN(4000000)
   const COOLDOWN_SECONDS = new anchor.BN(60)
+  const GLOBAL_COOLDOWN_SECONDS = new anchor.BN(30)
 
   async function airdrop(pubKey: PublicKey, sol = 2) {
     const sig = await conn.connection.requestAirdrop(pubKey, sol * LAMPORTS_PER_SOL)
@@ -58,19 +60,30 @@ describe("tap-shield", () => {
 
     faucetRegistryPda = getFaucetRegistryPda(operator.publicKey)
     userClaimRegistryPda = getUserClaimRegistryPda(claimer.publicKey)
-
+    ;[protocolConfigPda] = PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId)
+
+    await program.methods
+      .initializeConfig(GLOBAL_COOLDOWN_SECONDS)
+      .accounts({
+        // `anchor test` deploys with the provider wallet as upgrade authority
+        admin: conn.wallet.publicKey,
+        protocolConfig: protocolConfigPda,
+        programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID)[0],
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc()
   })
 
   describe("Initialize Faucet", () => {
     it("Initializing the Faucet Registry", async () => {
       const accounts = {
-        operator: operator.publicKey,
+        owner: operator.publicKey,
         faucetRegistry: faucetRegistryPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       }
 
       const txn = await program.methods
-        .initializeFaucet(TEST_FAUCET_NAME)
+        .initializeFaucet(operator.publicKey, TEST_FAUCET_NAME, COOLDOWN_SECONDS)
         .accounts(accounts)
         .signers([operator])
         .rpc()
@@ -79,21 +92,23 @@ describe("tap-shield", () => {
 
       const faucetAccount = await program.account.faucetRegistry.fetch(faucetRegistryPda)
 
-      expect(faucetAccount.operator.toString()).to.equal(operator.publicKey.toString())
+      expect(faucetAccount.owner.toString()).to.equal(operator.publicKey.toString())
+      expect(faucetAccount.faucetId.toString()).to.equal(operator.publicKey.toString())
       expect(faucetAccount.name).to.equal(TEST_FAUCET_NAME)
       expect(faucetAccount.totalClaims.toNumber()).to.equal(0)
       expect(faucetAccount.createdAt.toNumber()).to.be.greaterThan(0)
+      expect(faucetAccount.cooldownSeconds.toNumber()).to.equal(COOLDOWN_SECONDS.toNumber())
     })
 
     it("Should fail if faucet already exists", async () => {
       try {
         const accounts = {
-          operator: operator.publicKey,
+          owner: operator.publicKey,
           faucetRegistry: faucetRegistryPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         }
 
-        await program.methods.initializeFaucet(TEST_FAUCET_NAME).accounts(accounts).signers([operator]).rpc()
+        await program.methods.initializeFaucet(operator.publicKey, TEST_FAUCET_NAME, COOLDOWN_SECONDS).accounts(accounts).signers([operator]).rpc()
 
         expect.fail("Should throw FaucetAlreadyExists err")
       } catch (err) {
@@ -111,12 +126,12 @@ describe("tap-shield", () => {
 
       try {
         const accounts = {
-          operator: newOperator.publicKey,
+          owner: newOperator.publicKey,
           faucetRegistry: newFaucetRegistryPdA,
           systemProgram: anchor.web3.SystemProgram.programId,
         }
 
-        await program.methods.initializeFaucet(TEST_FAUCET_LONG_NAME).accounts(accounts).signers([operator]).rpc()
+        await program.methods.initializeFaucet(newOperator.publicKey, TEST_FAUCET_LONG_NAME, COOLDOWN_SECONDS).accounts(accounts).signers([operator]).rpc()
 
         expect.fail("Should throw FaucetNameTooLong err")
       } catch (err) {
@@ -137,12 +152,25 @@ describe("tap-shield", () => {
         operator: operator.publicKey,
         claimer: claimer.publicKey,
         faucetRegistry: faucetRegistryPda,
+        protocolConfig: protocolConfigPda,
         claimRecord: claimRecordPda,
         userClaimRegistry: userClaimRegistryPda,
-        systemProgram: anchor.web3.SystemProgram.programId
+        userFaucetState: PublicKey.findProgramAddressSync([Buffer.from("user_faucet"), claimer.publicKey.toBuffer(), faucetRegistryPda.toBuffer()], program.programId)[0],
+        protocolBlockEntry: PublicKey.findProgramAddressSync([Buffer.from("block"), claimer.publicKey.toBuffer()], program.programId)[0],
+        faucetListEntry: PublicKey.findProgramAddressSync([Buffer.from("list"), faucetRegistryPda.toBuffer(), claimer.publicKey.toBuffer()], program.programId)[0],
+        claimerBond: PublicKey.findProgramAddressSync([Buffer.from("bond"), claimer.publicKey.toBuffer()], program.programId)[0],
+        clusterMembership: PublicKey.findProgramAddressSync([Buffer.from("cluster_member"), faucetRegistryPda.toBuffer(), claimer.publicKey.toBuffer()], program.programId)[0],
+        walletCluster: null,
+        instructionSysvar: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        claimLog: null,
       }
 
-      const txn = await program.methods.recordClaim(claimer.publicKey, CLAIM_AMOUNT, COOLDOWN_SECONDS).accounts(accounts).signers([operator]).rpc()
+      // The claimer co-signs, otherwise this unapproved faucet leaves the global user registry untouched
+      const ix = await program.methods.recordClaim(claimer.publicKey, CLAIM_AMOUNT).accounts(accounts).instruction()
+      ix.keys.find((meta) => meta.pubkey.equals(claimer.publicKey))!.isSigner = true
+
+      const txn = await conn.sendAndConfirm(new anchor.web3.Transaction().add(ix), [operator, claimer])
 
       console.log("Record claim txn: ", txn)
 
@@ -154,11 +182,18 @@ describe("tap-shield", () => {
 
       const faucetAccount = await program.account.faucetRegistry.fetch(faucetRegistryPda)
       expect(faucetAccount.totalClaims.toNumber()).to.equal(1)
+      expect(faucetAccount.totalAmount.toNumber()).to.equal(CLAIM_AMOUNT.toNumber())
+      expect(faucetAccount.uniqueClaimers.toNumber()).to.equal(1)
 
       const userRegistry = await program.account.userClaimRegistry.fetch(userClaimRegistryPda)
       expect(userRegistry.user.toString()).to.equal(claimer.publicKey.toString())
       expect(userRegistry.lastFaucet.toString()).to.equal(faucetRegistryPda.toString())
       expect(userRegistry.totalClaimsAcrossFaucets.toNumber()).to.equal(1)
+      expect(userRegistry.bump).to.equal(PublicKey.findProgramAddressSync([Buffer.from("user_registry"), claimer.publicKey.toBuffer()], program.programId)[1])
+
+      const userFaucetState = await program.account.userFaucetState.fetch(PublicKey.findProgramAddressSync([Buffer.from("user_faucet"), claimer.publicKey.toBuffer(), faucetRegistryPda.toBuffer()], program.programId)[0])
+      expect(userFaucetState.claimCount.toNumber()).to.equal(1)
+      expect(userFaucetState.totalAmount.toNumber()).to.equal(CLAIM_AMOUNT.toNumber())
     })
 
     it("Should fail when claiming to soon", async () => {
@@ -170,18 +205,52 @@ describe("tap-shield", () => {
         operator: operator.publicKey,
         claimer: claimer.publicKey,
         faucetRegistry: faucetRegistryPda,
+        protocolConfig: protocolConfigPda,
         claimRecord: nextClaimPda,
         userClaimRegistry: userClaimRegistryPda,
-        systemProgram: anchor.web3.SystemProgram.programId
+        userFaucetState: PublicKey.findProgramAddressSync([Buffer.from("user_faucet"), claimer.publicKey.toBuffer(), faucetRegistryPda.toBuffer()], program.programId)[0],
+        protocolBlockEntry: PublicKey.findProgramAddressSync([Buffer.from("block"), claimer.publicKey.toBuffer()], program.programId)[0],
+        faucetListEntry: PublicKey.findProgramAddressSync([Buffer.from("list"), faucetRegistryPda.toBuffer(), claimer.publicKey.toBuffer()], program.programId)[0],
+        claimerBond: PublicKey.findProgramAddressSync([Buffer.from("bond"), claimer.publicKey.toBuffer()], program.programId)[0],
+        clusterMembership: PublicKey.findProgramAddressSync([Buffer.from("cluster_member"), faucetRegistryPda.toBuffer(), claimer.publicKey.toBuffer()], program.programId)[0],
+        walletCluster: null,
+        instructionSysvar: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        claimLog: null,
       }
 
       try {
-        await program.methods.recordClaim(claimer.publicKey, CLAIM_AMOUNT, COOLDOWN_SECONDS).accounts(accounts).signers([operator]).rpc()
+        await program.methods.recordClaim(claimer.publicKey, CLAIM_AMOUNT).accounts(accounts).signers([operator]).rpc()
         expect.fail("Should throw ClaimTooRecent err")
       }
       catch (err) {
         console.log("ClaimTooRecent err");
       }
     })
+
+    it("Should report the remaining cooldown without recording a claim", async () => {
+      const eligibility = await program.methods
+        .checkEligibility(CLAIM_AMOUNT)
+        .accounts({
+          claimer: claimer.publicKey,
+          faucetRegistry: faucetRegistryPda,
+          protocolConfig: protocolConfigPda,
+          userClaimRegistry: userClaimRegistryPda,
+          userFaucetState: PublicKey.findProgramAddressSync([Buffer.from("user_faucet"), claimer.publicKey.toBuffer(), faucetRegistryPda.toBuffer()], program.programId)[0],
+          protocolBlockEntry: PublicKey.findProgramAddressSync([Buffer.from("block"), claimer.publicKey.toBuffer()], program.programId)[0],
+          faucetListEntry: PublicKey.findProgramAddressSync([Buffer.from("list"), faucetRegistryPda.toBuffer(), claimer.publicKey.toBuffer()], program.programId)[0],
+          claimerBond: PublicKey.findProgramAddressSync([Buffer.from("bond"), claimer.publicKey.toBuffer()], program.programId)[0],
+          clusterMembership: PublicKey.findProgramAddressSync([Buffer.from("cluster_member"), faucetRegistryPda.toBuffer(), claimer.publicKey.toBuffer()], program.programId)[0],
+          walletCluster: null,
+        })
+        .view()
+
+      expect(eligibility.eligible).to.equal(false)
+      expect(eligibility.secondsRemaining.toNumber()).to.be.greaterThan(0)
+      expect(eligibility.secondsRemaining.toNumber()).to.be.at.most(COOLDOWN_SECONDS.toNumber())
+
+      const faucetAccount = await program.account.faucetRegistry.fetch(faucetRegistryPda)
+      expect(faucetAccount.totalClaims.toNumber()).to.equal(1)
+    })
   })
 })